@@ -0,0 +1,346 @@
+//! Derive macros for `simple-bytes`, use them with the `derive` feature.
+//!
+//! `#[derive(FromBytes, ToBytes)]` reads or writes every field of a struct
+//! with named fields in order. Fields are read with `FromBytes` and written
+//! with `ToBytes`, so numbers use big-endian.
+//!
+//! ## Field attributes
+//! - `#[bytes(le)]` reads or writes a number in little-endian.
+//! - `#[bytes(len_prefix = "u32")]` prefixes a `Vec` or `String` with its
+//!   length, allowed are `u8`, `u16`, `u32` and `u64`. Combined with `le`
+//!   the prefix is little-endian.
+//!
+//! ## Example
+//! ```
+//! use simple_bytes::{BytesOwned, BytesRead, BytesWrite, BytesSeek};
+//! use simple_bytes::{FromBytes, ToBytes};
+//!
+//! #[derive(Debug, PartialEq, FromBytes, ToBytes)]
+//! struct Packet {
+//!     kind: u8,
+//!     #[bytes(le)]
+//!     id: u32,
+//!     #[bytes(len_prefix = "u16")]
+//!     name: String
+//! }
+//!
+//! let packet = Packet { kind: 1, id: 2, name: "hey".into() };
+//! let mut bytes = BytesOwned::new();
+//! bytes.write_typed(&packet);
+//! assert_eq!(bytes.as_slice(), &[1, 2, 0, 0, 0, 0, 3, b'h', b'e', b'y']);
+//!
+//! bytes.seek(0);
+//! assert_eq!(bytes.read_typed::<Packet>(), packet);
+//! ```
+
+use proc_macro::{TokenStream, TokenTree, Delimiter};
+
+/// Implements `FromBytes`, see the crate documentation.
+#[proc_macro_derive(FromBytes, attributes(bytes))]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+	let out = parse_struct(input)
+		.map(|s| from_bytes_impl(&s))
+		.unwrap_or_else(compile_error);
+
+	out.parse().expect("generated invalid code")
+}
+
+/// Implements `ToBytes`, see the crate documentation.
+#[proc_macro_derive(ToBytes, attributes(bytes))]
+pub fn derive_to_bytes(input: TokenStream) -> TokenStream {
+	let out = parse_struct(input)
+		.map(|s| to_bytes_impl(&s))
+		.unwrap_or_else(compile_error);
+
+	out.parse().expect("generated invalid code")
+}
+
+struct Struct {
+	name: String,
+	fields: Vec<Field>
+}
+
+struct Field {
+	name: String,
+	ty: String,
+	le: bool,
+	len_prefix: Option<String>
+}
+
+fn compile_error(msg: String) -> String {
+	format!("::core::compile_error!({:?});", msg)
+}
+
+fn parse_struct(input: TokenStream) -> Result<Struct, String> {
+	let mut tokens = input.into_iter().peekable();
+	let mut name = None;
+
+	while let Some(token) = tokens.next() {
+		match token {
+			TokenTree::Ident(ident) if ident.to_string() == "struct" => {
+				match tokens.next() {
+					Some(TokenTree::Ident(ident)) => {
+						name = Some(ident.to_string());
+						break
+					},
+					_ => return Err("expected a struct name".into())
+				}
+			},
+			TokenTree::Ident(ident) if ident.to_string() == "enum"
+				|| ident.to_string() == "union" =>
+			{
+				return Err("only structs are supported".into())
+			},
+			_ => {}
+		}
+	}
+
+	let name = name.ok_or("expected a struct")?;
+
+	match tokens.next() {
+		Some(TokenTree::Group(group))
+			if group.delimiter() == Delimiter::Brace =>
+		{
+			let fields = parse_fields(group.stream())?;
+			Ok(Struct { name, fields })
+		},
+		Some(TokenTree::Punct(p)) if p.as_char() == '<' => {
+			Err("generic structs are not supported".into())
+		},
+		_ => Err("only structs with named fields are supported".into())
+	}
+}
+
+fn parse_fields(stream: TokenStream) -> Result<Vec<Field>, String> {
+	let mut fields = vec![];
+	let mut current: Vec<TokenTree> = vec![];
+	// tracks <> so commas inside of generics don't split a field
+	let mut depth = 0usize;
+
+	for token in stream {
+		match &token {
+			TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+			TokenTree::Punct(p) if p.as_char() == '>' => {
+				depth = depth.saturating_sub(1)
+			},
+			TokenTree::Punct(p) if p.as_char() == ',' && depth == 0 => {
+				fields.push(parse_field(std::mem::take(&mut current))?);
+				continue
+			},
+			_ => {}
+		}
+
+		current.push(token);
+	}
+
+	if !current.is_empty() {
+		fields.push(parse_field(current)?);
+	}
+
+	Ok(fields)
+}
+
+fn parse_field(tokens: Vec<TokenTree>) -> Result<Field, String> {
+	let mut tokens = tokens.into_iter().peekable();
+	let mut le = false;
+	let mut len_prefix = None;
+
+	// attributes
+	while matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '#') {
+		tokens.next();
+		let group = match tokens.next() {
+			Some(TokenTree::Group(g)) => g,
+			_ => return Err("expected an attribute".into())
+		};
+
+		let mut attr = group.stream().into_iter();
+		match attr.next() {
+			Some(TokenTree::Ident(ident)) if ident.to_string() == "bytes" => {},
+			_ => continue
+		}
+
+		match attr.next() {
+			Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
+				parse_bytes_attr(g.stream(), &mut le, &mut len_prefix)?;
+			},
+			_ => return Err("expected #[bytes(...)]".into())
+		}
+	}
+
+	// visibility
+	if matches!(tokens.peek(), Some(TokenTree::Ident(i)) if i.to_string() == "pub") {
+		tokens.next();
+		if matches!(
+			tokens.peek(),
+			Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis
+		) {
+			tokens.next();
+		}
+	}
+
+	let name = match tokens.next() {
+		Some(TokenTree::Ident(ident)) => ident.to_string(),
+		_ => return Err("expected a field name".into())
+	};
+
+	match tokens.next() {
+		Some(TokenTree::Punct(p)) if p.as_char() == ':' => {},
+		_ => return Err("expected a `:` after the field name".into())
+	}
+
+	let ty: TokenStream = tokens.collect();
+	if ty.is_empty() {
+		return Err(format!("expected a type for field `{}`", name))
+	}
+
+	Ok(Field { name, ty: ty.to_string(), le, len_prefix })
+}
+
+fn parse_bytes_attr(
+	stream: TokenStream,
+	le: &mut bool,
+	len_prefix: &mut Option<String>
+) -> Result<(), String> {
+	let mut tokens = stream.into_iter();
+
+	while let Some(token) = tokens.next() {
+		let key = match token {
+			TokenTree::Ident(ident) => ident.to_string(),
+			TokenTree::Punct(p) if p.as_char() == ',' => continue,
+			_ => return Err("expected `le`, `be` or `len_prefix`".into())
+		};
+
+		match key.as_str() {
+			"le" => *le = true,
+			"be" => *le = false,
+			"len_prefix" => {
+				match tokens.next() {
+					Some(TokenTree::Punct(p)) if p.as_char() == '=' => {},
+					_ => return Err("expected `len_prefix = \"..\"`".into())
+				}
+
+				let lit = match tokens.next() {
+					Some(TokenTree::Literal(lit)) => lit.to_string(),
+					_ => return Err("expected `len_prefix = \"..\"`".into())
+				};
+
+				let ty = lit.trim_matches('"');
+				match ty {
+					"u8" | "u16" | "u32" | "u64" => {
+						*len_prefix = Some(ty.to_string())
+					},
+					_ => return Err(format!(
+						"len_prefix `{}` is not supported, use u8, u16, u32 or u64",
+						ty
+					))
+				}
+			},
+			_ => return Err(format!("unknown bytes attribute `{}`", key))
+		}
+	}
+
+	Ok(())
+}
+
+fn endian_suffix(le: bool) -> &'static str {
+	if le { "le" } else { "be" }
+}
+
+fn from_bytes_impl(s: &Struct) -> String {
+	let mut fields = String::new();
+	for field in &s.fields {
+		let read = match (&field.len_prefix, field.le) {
+			(Some(prefix), le) => format!(
+				"{{
+					let len = <{prefix} as ::simple_bytes::__StructField>\
+						::try_read_{endian}(r)?;
+					let len = <usize as ::core::convert::TryFrom<{prefix}>>\
+						::try_from(len)
+						.map_err(|_| ::simple_bytes::ReadError)?;
+					<{ty} as ::simple_bytes::__ReadLenPrefixed>\
+						::try_read_len_prefixed(r, len)?
+				}}",
+				prefix = prefix,
+				endian = endian_suffix(le),
+				ty = field.ty
+			),
+			(None, true) => format!(
+				"<{} as ::simple_bytes::__StructField>::try_read_le(r)?",
+				field.ty
+			),
+			(None, false) => format!(
+				"<{} as ::simple_bytes::FromBytes>::from_bytes(r)?",
+				field.ty
+			)
+		};
+
+		fields.push_str(&format!("{}: {},\n", field.name, read));
+	}
+
+	format!(
+		"impl ::simple_bytes::FromBytes for {name} {{
+			#[allow(unused_variables)]
+			fn from_bytes<R: ::simple_bytes::BytesRead>(
+				r: &mut R
+			) -> ::core::result::Result<Self, ::simple_bytes::ReadError> {{
+				::core::result::Result::Ok(Self {{
+					{fields}
+				}})
+			}}
+		}}",
+		name = s.name,
+		fields = fields
+	)
+}
+
+fn to_bytes_impl(s: &Struct) -> String {
+	let mut writes = String::new();
+	for field in &s.fields {
+		let write = match (&field.len_prefix, field.le) {
+			(Some(prefix), le) => format!(
+				"let len = <{ty} as ::simple_bytes::__WriteLenPrefixed>\
+					::prefix_len(&self.{name});
+				let len = <{prefix} as ::core::convert::TryFrom<usize>>\
+					::try_from(len)
+					.map_err(|_| ::simple_bytes::WriteError)?;
+				<{prefix} as ::simple_bytes::__StructField>\
+					::try_write_{endian}(len, w)?;
+				<{ty} as ::simple_bytes::__WriteLenPrefixed>\
+					::try_write_len_prefixed(&self.{name}, w)?;",
+				prefix = prefix,
+				endian = endian_suffix(le),
+				ty = field.ty,
+				name = field.name
+			),
+			(None, true) => format!(
+				"<{} as ::simple_bytes::__StructField>\
+					::try_write_le(self.{}, w)?;",
+				field.ty,
+				field.name
+			),
+			(None, false) => format!(
+				"<{} as ::simple_bytes::ToBytes>::to_bytes(&self.{}, w)?;",
+				field.ty,
+				field.name
+			)
+		};
+
+		writes.push_str(&write);
+		writes.push('\n');
+	}
+
+	format!(
+		"impl ::simple_bytes::ToBytes for {name} {{
+			#[allow(unused_variables)]
+			fn to_bytes<W: ::simple_bytes::BytesWrite>(
+				&self,
+				w: &mut W
+			) -> ::core::result::Result<(), ::simple_bytes::WriteError> {{
+				{writes}
+				::core::result::Result::Ok(())
+			}}
+		}}",
+		name = s.name,
+		writes = writes
+	)
+}
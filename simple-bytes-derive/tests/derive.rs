@@ -0,0 +1,89 @@
+
+use simple_bytes::{
+	Bytes, BytesOwned, BytesMut, BytesRead, BytesWrite, BytesSeek,
+	FromBytes, ToBytes
+};
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes)]
+struct Inner {
+	a: u16,
+	#[bytes(le)]
+	b: i32
+}
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes)]
+pub struct Outer {
+	/// the version
+	pub version: u8,
+	pub(crate) inner: Inner,
+	#[bytes(len_prefix = "u8")]
+	items: Vec<u16>,
+	#[bytes(le, len_prefix = "u16")]
+	name: String,
+	float: f64
+}
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes)]
+struct Empty {}
+
+fn outer() -> Outer {
+	Outer {
+		version: 1,
+		inner: Inner { a: 2, b: -2 },
+		items: vec![3, 4],
+		name: "hey".into(),
+		float: 1.5
+	}
+}
+
+#[test]
+fn round_trip() {
+	let mut bytes = BytesOwned::new();
+	bytes.write_typed(&outer());
+	assert_eq!(&bytes.as_slice()[..17], &[
+		1,
+		0, 2, 0xfe, 0xff, 0xff, 0xff,
+		2, 0, 3, 0, 4,
+		3, 0, b'h', b'e', b'y'
+	]);
+	assert_eq!(&bytes.as_slice()[17..], 1.5f64.to_be_bytes());
+
+	bytes.seek(0);
+	assert_eq!(bytes.read_typed::<Outer>(), outer());
+	assert!(bytes.remaining().is_empty());
+
+	bytes.seek(0);
+	assert_eq!(bytes.read_typed::<Empty>(), Empty {});
+	assert_eq!(bytes.position(), 0);
+}
+
+#[test]
+fn invalid() {
+	// truncated
+	let mut bytes = Bytes::from_array(&[1, 0, 2, 0, 0]);
+	assert!(bytes.try_read_typed::<Outer>().is_err());
+
+	// invalid utf8
+	let mut bytes = Bytes::from_array(&[0, 1, 0xff, 0]);
+	#[derive(Debug, FromBytes)]
+	struct Name {
+		#[bytes(len_prefix = "u16")]
+		_name: String
+	}
+	assert!(bytes.try_read_typed::<Name>().is_err());
+	assert_eq!(bytes.position(), 2);
+}
+
+#[test]
+fn prefix_too_long() {
+	#[derive(ToBytes)]
+	struct Items {
+		#[bytes(len_prefix = "u8")]
+		items: Vec<u8>
+	}
+
+	let mut buf = [0u8; 512];
+	let mut bytes = BytesMut::from(&mut buf[..]);
+	let items = Items { items: vec![0; 256] };
+	assert!(bytes.try_write_typed(&items).is_err());
+}
@@ -0,0 +1,183 @@
+use crate::{BytesRead, ReadError, BytesReadRef, BytesSeek, SeekError, BytesBase};
+
+/// A reader with a total budget of bytes that can be read, regardless
+/// of how many bytes remain.
+///
+/// Every successful read consumes its length from the budget, once the
+/// budget is exhausted every read fails. Seeking does not restore the
+/// budget. Useful to cap the work done on an untrusted nested input.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{BudgetReader, Bytes, BytesRead};
+/// let mut bytes = BudgetReader::new(Bytes::from_array(&[0u8; 8]), 6);
+/// assert!(bytes.try_read(4).is_ok());
+/// assert!(bytes.try_read(4).is_err());
+/// assert_eq!(bytes.budget(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BudgetReader<T> {
+	budget: usize,
+	inner: T
+}
+
+impl<T> BudgetReader<T> {
+	/// Creates a new BudgetReader.
+	pub fn new(inner: T, budget: usize) -> Self {
+		Self { budget, inner }
+	}
+
+	/// Returns how many bytes can still be read.
+	pub fn budget(&self) -> usize {
+		self.budget
+	}
+
+	/// Returns the inner value as a reference.
+	pub fn inner(&self) -> &T {
+		&self.inner
+	}
+
+	/// Returns the inner value as a mutable reference.
+	pub fn inner_mut(&mut self) -> &mut T {
+		&mut self.inner
+	}
+
+	/// Returns the inner value, discarding the budget.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+
+impl<T> BytesRead for BudgetReader<T>
+where T: BytesRead {
+	#[inline]
+	fn as_slice(&self) -> &[u8] {
+		self.inner.as_slice()
+	}
+
+	#[inline]
+	fn remaining(&self) -> &[u8] {
+		self.inner.remaining()
+	}
+
+	/// Try to read a given length of bytes.
+	///
+	/// ## Fails
+	/// If len exceeds the budget or `self.remaining().len()`.
+	#[inline]
+	fn try_read(&mut self, len: usize) -> Result<&[u8], ReadError> {
+		if len > self.budget {
+			return Err(ReadError)
+		}
+
+		let slice = self.inner.try_read(len)?;
+		self.budget -= len;
+		Ok(slice)
+	}
+
+	#[inline]
+	fn peek(&self, len: usize) -> Option<&[u8]> {
+		if len > self.budget {
+			return None
+		}
+
+		self.inner.peek(len)
+	}
+}
+
+impl<'a, T> BytesReadRef<'a> for BudgetReader<T>
+where T: BytesReadRef<'a> {
+	#[inline]
+	fn as_slice_ref(&self) -> &'a [u8] {
+		self.inner.as_slice_ref()
+	}
+
+	#[inline]
+	fn remaining_ref(&self) -> &'a [u8] {
+		self.inner.remaining_ref()
+	}
+
+	#[inline]
+	fn try_read_ref(&mut self, len: usize) -> Result<&'a [u8], ReadError> {
+		if len > self.budget {
+			return Err(ReadError)
+		}
+
+		let slice = self.inner.try_read_ref(len)?;
+		self.budget -= len;
+		Ok(slice)
+	}
+
+	#[inline]
+	fn peek_ref(&self, len: usize) -> Option<&'a [u8]> {
+		if len > self.budget {
+			return None
+		}
+
+		self.inner.peek_ref(len)
+	}
+}
+
+impl<T> BytesSeek for BudgetReader<T>
+where T: BytesSeek {
+	fn position(&self) -> usize {
+		self.inner.position()
+	}
+
+	fn absolute_position(&self) -> usize {
+		self.inner.absolute_position()
+	}
+
+	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
+		self.inner.try_seek(pos)
+	}
+}
+
+impl<T> BytesBase for BudgetReader<T>
+where T: BytesBase {
+	type Base = T::Base;
+
+	#[inline]
+	fn base(&self) -> &T::Base {
+		self.inner.base()
+	}
+
+	#[inline]
+	fn base_mut(&mut self) -> &mut T::Base {
+		self.inner.base_mut()
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Bytes;
+
+	#[test]
+	fn read() {
+		let bytes: Vec<u8> = (0..20).collect();
+		let mut bytes = BudgetReader::new(Bytes::from(bytes.as_slice()), 10);
+		assert_eq!(bytes.read_u32(), 0x00010203);
+		assert_eq!(bytes.budget(), 6);
+		assert!(bytes.try_read_u64().is_err());
+		assert!(bytes.peek(7).is_none());
+		assert_eq!(bytes.budget(), 6);
+		assert_eq!(bytes.read_ref(4), &[4, 5, 6, 7]);
+
+		// seeking back does not restore the budget
+		bytes.seek(0);
+		assert!(bytes.try_read(3).is_err());
+		assert_eq!(bytes.read(2), &[0, 1]);
+		assert_eq!(bytes.budget(), 0);
+		assert!(bytes.try_read_u8().is_err());
+		assert_eq!(bytes.read(0), &[]);
+	}
+
+	#[test]
+	fn skip_leading_over_budget() {
+		let mut bytes = BudgetReader::new(Bytes::from(&[0u8, 0, 0, 1][..]), 2);
+		assert_eq!(bytes.skip_leading(0), 2);
+		assert_eq!(bytes.remaining(), &[0, 1]);
+	}
+}
@@ -2,6 +2,7 @@
 use crate::{BytesRead, ReadError, BytesReadRef, BytesSeek, SeekError, Cursor};
 
 use std::io;
+use std::ops::{Index, Range};
 
 /// A slice wrapper that implements BytesRead.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,10 +27,51 @@ impl<'a> Bytes<'a> {
 		Self { inner: cursor }
 	}
 
+	/// Creates a Bytes over an array with the position `0`.
+	pub fn from_array<const N: usize>(arr: &'a [u8; N]) -> Self {
+		Self::from(&arr[..])
+	}
+
+	/// Creates a Bytes over `slice[range]` with the position `0`.
+	///
+	/// Returns `None` if the range is out of bounds.
+	pub fn from_range(slice: &'a [u8], range: Range<usize>) -> Option<Self> {
+		slice.get(range).map(Self::from)
+	}
+
 	/// Returns the inner slice with the original reference.
 	pub fn inner(&self) -> &'a [u8] {
 		self.as_slice_ref()
 	}
+
+	/// Returns `true` if both point at the same region of the same buffer,
+	/// the position is ignored.
+	///
+	/// Equal content in different buffers or overlapping but different
+	/// regions return `false`.
+	pub fn same_buffer(&self, other: &Bytes<'a>) -> bool {
+		let a = self.as_slice_ref();
+		let b = other.as_slice_ref();
+		a.as_ptr() == b.as_ptr() && a.len() == b.len()
+	}
+
+	/// Sets the position, for example `Bytes::from(slice).at(4).read_u32()`.
+	///
+	/// ## Fails
+	/// If the position exceeds the slice length.
+	pub fn try_at(mut self, position: usize) -> Result<Self, SeekError> {
+		self.try_seek(position)?;
+		Ok(self)
+	}
+
+	/// Sets the position, for example `Bytes::from(slice).at(4).read_u32()`.
+	///
+	/// ## Panics
+	/// If the position exceeds the slice length.
+	#[track_caller]
+	pub fn at(self, position: usize) -> Self {
+		self.try_at(position).expect("failed to seek")
+	}
 }
 
 impl BytesRead for Bytes<'_> {
@@ -66,7 +108,7 @@ impl<'a> BytesReadRef<'a> for Bytes<'a> {
 	// returns the full slice
 	#[inline]
 	fn as_slice_ref(&self) -> &'a [u8] {
-		*self.inner.inner()
+		self.inner.inner()
 	}
 
 	#[inline]
@@ -113,12 +155,59 @@ impl io::Seek for Bytes<'_> {
 	}
 }
 
+impl Index<usize> for Bytes<'_> {
+	type Output = u8;
+
+	/// Returns the byte at the absolute `index`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `index` is out of bounds.
+	#[inline]
+	fn index(&self, index: usize) -> &u8 {
+		&self.as_slice()[index]
+	}
+}
+
+impl Index<Range<usize>> for Bytes<'_> {
+	type Output = [u8];
+
+	/// Returns the bytes in the absolute `range`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `range` is out of bounds.
+	#[inline]
+	fn index(&self, range: Range<usize>) -> &[u8] {
+		&self.as_slice()[range]
+	}
+}
+
 impl<'a> From<&'a [u8]> for Bytes<'a> {
 	fn from(s: &'a [u8]) -> Self {
 		Self::new(0, s)
 	}
 }
 
+macro_rules! try_from_bytes_impl {
+	($($type:ty),*) => ($(
+		impl TryFrom<Bytes<'_>> for $type {
+			type Error = ReadError;
+
+			/// Converts the remaining bytes into a number (in big-endian).
+			///
+			/// Fails if the remaining length is not exactly the size
+			/// of the type.
+			fn try_from(bytes: Bytes<'_>) -> Result<Self, ReadError> {
+				bytes.remaining()
+					.try_into()
+					.map(<$type>::from_be_bytes)
+					.map_err(|_| ReadError)
+			}
+		}
+	)*)
+}
+
+try_from_bytes_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
 #[cfg(test)]
 mod tests {
 
@@ -165,6 +254,469 @@ mod tests {
 
 	}
 
+	#[test]
+	fn read_enum() {
+		#[derive(Debug, PartialEq)]
+		enum Tag { A, B }
+
+		impl TryFrom<u8> for Tag {
+			type Error = ();
+
+			fn try_from(num: u8) -> Result<Self, ()> {
+				match num {
+					1 => Ok(Self::A),
+					2 => Ok(Self::B),
+					_ => Err(())
+				}
+			}
+		}
+
+		let mut bytes = Bytes::from(&[1u8, 2, 3][..]);
+		assert_eq!(bytes.read_enum_u8::<Tag>(), Tag::A);
+		assert_eq!(bytes.try_read_enum_u8::<Tag>().unwrap(), Tag::B);
+		assert!(bytes.try_read_enum_u8::<Tag>().is_err());
+		// the unknown tag should not have been read
+		assert_eq!(bytes.position(), 2);
+
+		let mut bytes = Bytes::from(&[0u8, 2][..]);
+		assert_eq!(bytes.read_enum_u16::<u16>(), 2);
+		assert!(bytes.try_read_enum_u32::<u32>().is_err());
+	}
+
+	#[test]
+	fn guarded() {
+		let mut bytes = Bytes::from(&[0u8, 1, 2][..]);
+		assert!(bytes.guarded(4, |b| b.read_u32()).is_err());
+		assert_eq!(bytes.position(), 0);
+
+		let num = bytes.guarded(3, |b| b.read_u16()).unwrap();
+		assert_eq!(num, 1);
+		assert_eq!(bytes.remaining(), &[2]);
+	}
+
+	#[test]
+	fn seek_from_end() {
+		use crate::SeekFromError;
+
+		let mut bytes = Bytes::from(&[1u8, 2, 3, 4][..]);
+		bytes.seek_from_end(1);
+		assert_eq!(bytes.position(), 3);
+		assert_eq!(bytes.position_from_end(), 1);
+		assert_eq!(bytes.read_u8(), 4);
+		assert_eq!(bytes.position_from_end(), 0);
+
+		assert_eq!(bytes.try_seek_from_end(5), Err(SeekFromError::BeforeStart));
+		assert_eq!(bytes.position(), 4);
+		bytes.seek_from_end(4);
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn from_array() {
+		let arr = [1u8, 2, 3];
+		let mut bytes = Bytes::from_array(&arr);
+		assert_eq!(bytes.len(), 3);
+		assert_eq!(bytes.read_u8(), 1);
+	}
+
+	#[test]
+	fn peek_next_u8() {
+		let mut bytes = Bytes::from_array(&[1u8, 2]);
+		assert_eq!(bytes.peek_u8(), Some(1));
+		assert_eq!(bytes.peek_u8(), Some(1));
+
+		let mut sum = 0;
+		while let Some(b) = bytes.next_u8() {
+			sum += b;
+		}
+		assert_eq!(sum, 3);
+		assert_eq!(bytes.peek_u8(), None);
+	}
+
+	#[test]
+	fn expect_magic() {
+		let mut bytes = Bytes::from_array(b"PNG\x01");
+		assert!(bytes.starts_with(b"PN"));
+		assert!(!bytes.starts_with(b"PNG\x01\x02"));
+		assert_eq!(bytes.expect_magic(b"PNX"), Err(ReadError));
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.expect_magic(b"PNG"), Ok(()));
+		assert_eq!(bytes.remaining(), &[1]);
+		assert!(bytes.starts_with(&[]));
+	}
+
+	#[test]
+	fn buffer_ends_with() {
+		let mut bytes = Bytes::from_array(b"abc\r\n");
+		assert!(bytes.buffer_ends_with(b"\r\n"));
+		assert!(!bytes.buffer_ends_with(b"\n\r"));
+		bytes.seek(5);
+		// the position doesn't matter
+		assert!(bytes.buffer_ends_with(b"abc\r\n"));
+	}
+
+	#[test]
+	fn read_at_offsets() {
+		let bytes: Vec<u8> = (0..10).collect();
+		let bytes = Bytes::from(bytes.as_slice());
+		let slices = bytes.read_at_offsets(&[(8, 2), (0, 1), (4, 0)]).unwrap();
+		assert_eq!(slices, [&[8, 9][..], &[0], &[]]);
+		assert!(bytes.read_at_offsets(&[(0, 1), (9, 2)]).is_err());
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn read_or_need() {
+		use crate::NeedError;
+
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3]);
+		assert_eq!(bytes.try_read_or_need(2), Ok(&[1, 2][..]));
+		assert_eq!(
+			bytes.try_read_or_need(4),
+			Err(NeedError::Truncated { needed: 3 })
+		);
+		assert_eq!(bytes.position(), 2);
+		assert_eq!(bytes.try_read_or_need(1), Ok(&[3][..]));
+	}
+
+	#[test]
+	fn read_with_remaining() {
+		let mut bytes = Bytes::from(&[1u8, 2, 3, 4][..]);
+		assert_eq!(bytes.read_with_remaining(3), (&[1u8, 2, 3][..], 1));
+		assert!(bytes.try_read_with_remaining(2).is_err());
+		assert_eq!(bytes.position(), 3);
+		assert_eq!(bytes.read_with_remaining(1), (&[4u8][..], 0));
+	}
+
+	#[test]
+	fn try_into_num() {
+		let bytes = Bytes::from_array(&[0u8, 0, 1, 2]);
+		assert_eq!(u32::try_from(bytes).unwrap(), 258);
+		assert!(u16::try_from(bytes).is_err());
+		assert!(u64::try_from(bytes).is_err());
+
+		let mut bytes = Bytes::from_array(&[1u8, 0, 2]);
+		bytes.seek(1);
+		assert_eq!(i16::try_from(bytes).unwrap(), 2);
+	}
+
+	#[test]
+	fn align() {
+		let mut bytes = Bytes::from_array(&[0u8; 6]);
+		assert!(bytes.is_aligned_to(4));
+		bytes.align_to(4);
+		assert_eq!(bytes.position(), 0);
+		bytes.seek(1);
+		assert!(!bytes.is_aligned_to(4));
+		assert!(bytes.is_aligned_to(1));
+		bytes.align_to(4);
+		assert_eq!(bytes.position(), 4);
+		bytes.seek(5);
+		assert!(bytes.try_align_to(4).is_err());
+		assert_eq!(bytes.position(), 5);
+	}
+
+	#[test]
+	#[should_panic]
+	fn align_not_power_of_two() {
+		Bytes::from_array(&[0u8; 6]).is_aligned_to(3);
+	}
+
+	#[test]
+	fn leading() {
+		let mut bytes = Bytes::from_array(&[0u8, 0, 0, 1, 1]);
+		assert_eq!(bytes.count_leading(1), 0);
+		assert_eq!(bytes.count_leading(0), 3);
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.skip_leading(0), 3);
+		assert_eq!(bytes.position(), 3);
+		// run reaching the end
+		assert_eq!(bytes.skip_leading(1), 2);
+		assert_eq!(bytes.position(), 5);
+		assert_eq!(bytes.skip_leading(1), 0);
+	}
+
+	#[test]
+	fn seek_error() {
+		let mut bytes = Bytes::from_array(&[1u8, 2]);
+		let e = bytes.try_seek(5).unwrap_err();
+		assert_eq!(e, SeekError { attempted: 5, max: 2 });
+		assert_eq!(e.to_string(), "tried to seek to 5 but the max position is 2");
+	}
+
+	#[test]
+	fn remaining_to_owned() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3]);
+		bytes.seek(1);
+		let owned = bytes.remaining_to_owned();
+		assert_eq!(owned.as_slice(), &[2, 3]);
+		assert_eq!(owned.position(), 0);
+		assert_eq!(bytes.position(), 1);
+	}
+
+	#[test]
+	fn len_or_rest() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3, 4]);
+		assert_eq!(bytes.read_len_or_rest(Some(1)), &[1]);
+		assert!(bytes.try_read_len_or_rest(Some(4)).is_err());
+		assert_eq!(bytes.read_len_or_rest(None), &[2, 3, 4]);
+		assert_eq!(bytes.read_remaining(), &[]);
+		assert_eq!(bytes.position(), 4);
+	}
+
+	#[test]
+	fn read_expect() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 0, 0, 3]);
+		assert!(bytes.read_expect(&[1, 3]).is_err());
+		assert_eq!(bytes.position(), 2);
+		bytes.seek(0);
+		bytes.read_expect(&[1, 2]).unwrap();
+		bytes.read_expect_zero(2).unwrap();
+		assert!(bytes.read_expect_zero(2).is_err());
+		assert_eq!(bytes.position(), 4);
+		assert!(bytes.read_expect_zero(1).is_err());
+		assert_eq!(bytes.position(), 5);
+	}
+
+	#[test]
+	fn peek_up_to() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3]);
+		assert_eq!(bytes.peek_up_to(2), &[1, 2]);
+		bytes.seek(1);
+		assert_eq!(bytes.peek_up_to(5), &[2, 3]);
+		assert_eq!(bytes.position(), 1);
+	}
+
+	#[test]
+	fn at_end() {
+		let mut bytes = Bytes::from_array(&[1u8, 2]);
+		let mut read = vec![];
+		while !bytes.at_end() {
+			assert_eq!(bytes.remaining_len(), 2 - read.len());
+			read.push(bytes.read_u8());
+		}
+		assert_eq!(read, [1, 2]);
+		assert_eq!(bytes.remaining_len(), 0);
+	}
+
+	#[test]
+	fn expect_consumed() {
+		let mut bytes = Bytes::from_array(&[1u8, 2]);
+		bytes.read_u8();
+		assert!(bytes.expect_consumed().is_err());
+		bytes.read_u8();
+		assert!(bytes.expect_consumed().is_ok());
+	}
+
+	#[test]
+	fn enumerate() {
+		let mut bytes = Bytes::from_array(&[5u8, 6, 7]);
+		bytes.seek(1);
+		let rem: Vec<_> = bytes.enumerate_remaining().collect();
+		assert_eq!(rem, [(1, 6), (2, 7)]);
+		let all: Vec<_> = bytes.enumerate_all().collect();
+		assert_eq!(all, [(0, 5), (1, 6), (2, 7)]);
+		assert_eq!(bytes.position(), 1);
+	}
+
+	#[test]
+	fn read_vec() {
+		let mut bytes = Bytes::from_array(&[0u8, 1, 0, 2, 0]);
+		let vec = bytes.try_read_vec(2, |b| b.try_read_u16()).unwrap();
+		assert_eq!(vec, [1, 2]);
+
+		bytes.seek(0);
+		assert!(bytes.try_read_vec(3, |b| b.try_read_u16()).is_err());
+		assert_eq!(bytes.position(), 0);
+
+		let vec = bytes.try_read_vec(usize::MAX, |_| Err::<u8, _>(ReadError));
+		assert!(vec.is_err());
+	}
+
+	#[test]
+	fn read_array_by_count_and_byte_len() {
+		let mut bytes = Bytes::from_array(&[0u8, 1, 0, 2, 0]);
+		assert_eq!(bytes.read_array_by_count(2, |b| b.try_read_u16()), [1, 2]);
+
+		bytes.seek(0);
+		assert_eq!(bytes.read_array_by_byte_len(4, |b| b.try_read_u16()), [1, 2]);
+		assert_eq!(bytes.position(), 4);
+
+		// the second element would cross the boundary
+		bytes.seek(0);
+		assert!(bytes.try_read_array_by_byte_len(3, |b| b.try_read_u16()).is_err());
+		assert_eq!(bytes.position(), 0);
+
+		assert!(bytes.try_read_array_by_byte_len(6, |b| b.try_read_u8()).is_err());
+		assert!(bytes.try_read_array_by_byte_len(2, |_| Ok(0u8)).is_err());
+		assert!(bytes.read_array_by_byte_len(0, |b| b.try_read_u8()).is_empty());
+	}
+
+	#[test]
+	fn io_seek() {
+		use std::io::{Seek, SeekFrom};
+
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3, 4]);
+		let _ = bytes.read_u16();
+		assert_eq!(bytes.stream_position().unwrap(), 2);
+		assert_eq!(Seek::seek(&mut bytes, SeekFrom::End(-1)).unwrap(), 3);
+		assert_eq!(bytes.read_u8(), 4);
+		assert_eq!(Seek::seek(&mut bytes, SeekFrom::Current(-2)).unwrap(), 2);
+		assert!(Seek::seek(&mut bytes, SeekFrom::End(1)).is_err());
+		assert!(Seek::seek(&mut bytes, SeekFrom::Current(-3)).is_err());
+		bytes.rewind().unwrap();
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.stream_position().unwrap(), 0);
+	}
+
+	#[test]
+	fn peek_endian() {
+		use crate::Endian;
+
+		let bytes = Bytes::from_array(b"II*\0");
+		assert_eq!(bytes.peek_u16_endian(Endian::Little), Some(0x4949));
+		assert_eq!(bytes.peek_u32_endian(Endian::Big), Some(0x49492a00));
+		assert_eq!(bytes.peek_u32_endian(Endian::Little), Some(0x002a4949));
+		assert_eq!(bytes.position(), 0);
+
+		let bytes = Bytes::from_array(&[1u8, 2, 3]);
+		assert_eq!(bytes.peek_u32_endian(Endian::Big), None);
+	}
+
+	#[test]
+	fn read_kv_map_u32() {
+		let mut bytes = Bytes::from(&[
+			0, 0, 0, 2,
+			0, 0, 0, 1, b'a', 0, 0, 0, 0,
+			0, 0, 0, 2, b'b', b'c', 0, 0, 0, 1, b'd',
+			1
+		][..]);
+		let map = bytes.try_read_kv_map_u32().unwrap();
+		assert_eq!(map, [(&b"a"[..], &b""[..]), (&b"bc"[..], &b"d"[..])]);
+		assert_eq!(bytes.remaining(), &[1]);
+
+		// the last value is truncated
+		let mut bytes = Bytes::from(&[
+			0, 0, 0, 2,
+			0, 0, 0, 1, b'a', 0, 0, 0, 0,
+			0, 0, 0, 1, b'b', 0, 0, 0, 2, b'c'
+		][..]);
+		assert!(bytes.try_read_kv_map_u32().is_err());
+		assert_eq!(bytes.position(), 0);
+
+		let mut bytes = Bytes::from(&[0xff; 4][..]);
+		assert!(bytes.try_read_kv_map_u32().is_err());
+	}
+
+	#[test]
+	fn read_kv_map_u32_ref() {
+		let data: &'static [u8] = &[0, 0, 0, 1, 0, 0, 0, 1, b'k', 0, 0, 0, 0, 1];
+		let mut bytes = Bytes::from(data);
+		let map: Vec<(&'static [u8], &'static [u8])> =
+			bytes.try_read_kv_map_u32_ref().unwrap();
+		assert_eq!(map, [(&b"k"[..], &b""[..])]);
+		assert_eq!(bytes.remaining(), &[1]);
+	}
+
+	#[test]
+	fn read_remaining_str() {
+		use crate::StrReadError;
+
+		let mut bytes = Bytes::from(&b"\x01hey"[..]);
+		bytes.seek(1);
+		assert_eq!(bytes.read_remaining_str(), "hey");
+		assert!(bytes.at_end());
+		assert_eq!(bytes.read_remaining_str(), "");
+
+		bytes.seek(1);
+		let s: &'static str = bytes.read_remaining_str_ref();
+		assert_eq!(s, "hey");
+
+		let mut bytes = Bytes::from(&[b'a', b'b', 0xff, b'c'][..]);
+		assert_eq!(
+			bytes.try_read_remaining_str(),
+			Err(StrReadError::InvalidUtf8 { valid_up_to: 2 })
+		);
+		assert_eq!(
+			bytes.try_read_remaining_str_ref(),
+			Err(StrReadError::InvalidUtf8 { valid_up_to: 2 })
+		);
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn read_str() {
+		use crate::StrReadError;
+
+		let mut bytes = Bytes::from_array(b"\x00\x00\x00\x02hi\x03h\xffi\x05h");
+		assert_eq!(bytes.read_str_u32(), "hi");
+		assert_eq!(
+			bytes.try_read_str_u8(),
+			Err(StrReadError::InvalidUtf8 { valid_up_to: 1 })
+		);
+		assert_eq!(bytes.position(), 10);
+		assert_eq!(
+			bytes.try_read_str_u8(),
+			Err(StrReadError::Truncated { needed: 4 })
+		);
+		assert_eq!(
+			bytes.try_read_str_u32(),
+			Err(StrReadError::Truncated { needed: 2 })
+		);
+		assert_eq!(bytes.position(), 10);
+
+		let mut bytes = Bytes::from_array(b"\x00\x00");
+		assert_eq!(bytes.read_str_u16(), "");
+
+		let mut bytes = Bytes::from_array(&[0xff; 5]);
+		assert_eq!(
+			bytes.try_read_str_u32(),
+			Err(StrReadError::Truncated { needed: u32::MAX as usize - 1 })
+		);
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn from_range() {
+		let slice = [1u8, 2, 3, 4];
+		let bytes = Bytes::from_range(&slice, 1..3).unwrap();
+		assert_eq!(bytes.as_slice(), &[2, 3]);
+		assert_eq!(bytes.position(), 0);
+		assert!(Bytes::from_range(&slice, 4..4).unwrap().is_empty());
+		assert!(Bytes::from_range(&slice, 3..5).is_none());
+		#[allow(clippy::reversed_empty_ranges)]
+		let range = 3..2;
+		assert!(Bytes::from_range(&slice, range).is_none());
+	}
+
+	#[test]
+	fn read_extended_len() {
+		use crate::ExtendedLen;
+
+		let mut bytes = Bytes::from_array(&[5u8, 0xff, 1, 0, 0xff, 0, 0, 1, 0, 0xff, 1]);
+		assert_eq!(bytes.read_extended_len_u8(0xff, ExtendedLen::U16), 5);
+		assert_eq!(bytes.read_extended_len_u8(0xff, ExtendedLen::U16), 256);
+		assert_eq!(bytes.read_extended_len_u8(0xff, ExtendedLen::U32), 256);
+		assert!(bytes.try_read_extended_len_u8(0xff, ExtendedLen::U16).is_err());
+		assert_eq!(bytes.position(), 9);
+		assert_eq!(bytes.read_extended_len_u8(0xfe, ExtendedLen::U16), 0xff);
+	}
+
+	#[test]
+	fn read_array() {
+		let mut bytes = Bytes::from_array(&[0u8, 1, 0, 2, 0, 3]);
+		assert_eq!(bytes.read_u16_array::<3>(), [1, 2, 3]);
+		bytes.seek(0);
+		assert_eq!(bytes.read_le_u16_array::<2>(), [256, 512]);
+		assert!(bytes.try_read_u16_array::<2>().is_err());
+		assert_eq!(bytes.position(), 4);
+		assert_eq!(bytes.read_u16_array::<0>(), []);
+
+		let floats = [1.5f32.to_be_bytes(), (-2f32).to_be_bytes()].concat();
+		let mut bytes = Bytes::from(floats.as_slice());
+		assert_eq!(bytes.read_f32_array(), [1.5, -2.0]);
+	}
+
 	#[test]
 	fn test_empty() {
 		let mut bytes = Bytes::from(&[][..]);
@@ -202,4 +754,148 @@ mod tests {
 		bytes.seek(101);
 	}
 
-}
\ No newline at end of file
+	#[test]
+	fn at() {
+		let buf = [0u8, 0, 0, 0, 0, 0, 0, 5];
+		assert_eq!(Bytes::from_array(&buf).at(4).read_u32(), 5);
+		assert_eq!(Bytes::from_array(&buf).try_at(8).unwrap().remaining(), &[]);
+		assert_eq!(
+			Bytes::from_array(&buf).try_at(9).unwrap_err(),
+			SeekError { attempted: 9, max: 8 }
+		);
+	}
+
+	#[test]
+	fn read_raw_and() {
+		let mut bytes = Bytes::from_array(&[9u8, 0, 3, b'a', b'b', b'c', 1]);
+		bytes.seek(1);
+		let (raw, s) = bytes.read_raw_and(|b| {
+			b.try_read_str_u16().map(str::to_string).map_err(|_| ReadError)
+		});
+		assert_eq!(raw, &[0, 3, b'a', b'b', b'c']);
+		assert_eq!(s, "abc");
+		assert_eq!(bytes.remaining(), &[1]);
+
+		assert!(bytes.try_read_raw_and(|b| b.try_read_u16()).is_err());
+		assert_eq!(bytes.position(), 6);
+
+		let res = bytes.try_read_raw_and(|b| {
+			b.seek(0);
+			Ok(())
+		});
+		assert!(res.is_err());
+		assert_eq!(bytes.position(), 6);
+	}
+
+	#[test]
+	fn index() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3, 4]);
+		let _ = bytes.read_u8();
+		assert_eq!(bytes[0], 1);
+		assert_eq!(&bytes[1..3], &[2, 3]);
+		assert_eq!(bytes.position(), 1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn index_out_of_bounds() {
+		let bytes = Bytes::from_array(&[1u8, 2]);
+		let _ = bytes[2];
+	}
+
+	#[test]
+	fn read_until_sentinel() {
+		// entries prefixed by their length, terminated by a zero length
+		let read_entry = |b: &mut Bytes| -> Result<Option<Vec<u8>>, ReadError> {
+			match b.try_read_u8()? {
+				0 => Ok(None),
+				len => b.try_read(len as usize).map(|s| Some(s.to_vec()))
+			}
+		};
+
+		let mut bytes = Bytes::from_array(&[1u8, 5, 2, 6, 7, 0, 9]);
+		let entries = bytes.read_until_sentinel(read_entry);
+		assert_eq!(entries, [vec![5], vec![6, 7]]);
+		assert_eq!(bytes.remaining(), &[9]);
+
+		// missing sentinel
+		let mut bytes = Bytes::from_array(&[1u8, 5, 2, 6, 7]);
+		assert!(bytes.try_read_until_sentinel(read_entry).is_err());
+		assert_eq!(bytes.position(), 0);
+
+		assert!(bytes.try_read_until_sentinel(|_| Ok(Some(0))).is_err());
+	}
+
+	#[test]
+	fn advance_saturating() {
+		let mut bytes = Bytes::from_array(&[0u8; 5]);
+		assert_eq!(bytes.advance_saturating(2), 2);
+		assert_eq!(bytes.advance_saturating(usize::MAX), 3);
+		assert_eq!(bytes.position(), 5);
+		assert_eq!(bytes.advance_saturating(1), 0);
+	}
+
+	#[test]
+	fn same_buffer() {
+		let buf = [1u8, 2, 3, 4];
+		let other = buf;
+
+		let a = Bytes::from_range(&buf, 1..3).unwrap();
+		let b = Bytes::from_range(&buf, 1..3).unwrap().at(1);
+		assert!(a.same_buffer(&b));
+
+		assert!(!a.same_buffer(&Bytes::from_range(&buf, 1..4).unwrap()));
+		assert!(!a.same_buffer(&Bytes::from_range(&buf, 0..2).unwrap()));
+		assert!(!a.same_buffer(&Bytes::from_range(&other, 1..3).unwrap()));
+		assert!(Bytes::from_array(&buf).same_buffer(&Bytes::from(&buf[..])));
+	}
+
+	#[test]
+	fn read_into_vec() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3, 4, 5]);
+		let mut out = Vec::with_capacity(2);
+		let ptr = out.as_ptr();
+
+		bytes.read_into_vec(2, &mut out);
+		assert_eq!(out, [1, 2]);
+		bytes.read_into_vec(2, &mut out);
+		assert_eq!(out, [3, 4]);
+		assert_eq!(out.as_ptr(), ptr);
+
+		assert!(bytes.try_read_into_vec(2, &mut out).is_err());
+		assert!(out.is_empty());
+		assert_eq!(bytes.remaining(), &[5]);
+	}
+
+	#[test]
+	fn try_seek_from() {
+		use std::io::SeekFrom;
+		use crate::SeekFromError;
+
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3, 4]);
+		assert_eq!(bytes.try_seek_from(SeekFrom::End(-1)).unwrap(), 3);
+		assert_eq!(bytes.try_seek_from(SeekFrom::Current(-2)).unwrap(), 1);
+		assert_eq!(bytes.try_seek_from(SeekFrom::Start(4)).unwrap(), 4);
+		assert_eq!(
+			bytes.try_seek_from(SeekFrom::Current(-5)).unwrap_err(),
+			SeekFromError::BeforeStart
+		);
+		assert_eq!(
+			bytes.try_seek_from(SeekFrom::End(1)).unwrap_err(),
+			SeekFromError::Seek(SeekError { attempted: 5, max: 4 })
+		);
+		assert_eq!(bytes.position(), 4);
+	}
+
+	#[test]
+	fn require() {
+		let mut bytes = Bytes::from_array(&[1u8, 2, 3]);
+		assert!(bytes.require(3).is_ok());
+		assert!(bytes.require(0).is_ok());
+		assert_eq!(bytes.require(4), Err(ReadError));
+
+		let _ = bytes.read_u8();
+		assert!(bytes.require(3).is_err());
+		assert_eq!(bytes.position(), 1);
+	}
+}
@@ -1,5 +1,5 @@
 //! # Note
-//!	Internally there exists only one position
+//! Internally there exists only one position
 //! So if you read and write you should keep this in mind
 
 use crate::{
@@ -10,10 +10,16 @@ use crate::{
 };
 
 use std::io;
+use std::ops::{Index, Range};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 
 /// A array wrapper that implements BytesWrite and BytesRead
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Equality, ordering and hashing only consider the content
+/// and ignore the position.
+#[derive(Debug, Clone)]
 pub struct BytesArray<const N: usize> {
 	inner: Cursor<[u8; N]>
 }
@@ -47,6 +53,50 @@ impl<const N: usize> BytesArray<N> {
 		self.inner.into_inner()
 	}
 
+	/// Sets the position, for example `BytesArray::from(arr).at(4).read_u32()`.
+	///
+	/// ## Fails
+	/// If the position exceeds `N`.
+	pub fn try_at(mut self, position: usize) -> Result<Self, SeekError> {
+		self.try_seek(position)?;
+		Ok(self)
+	}
+
+	/// Sets the position, for example `BytesArray::from(arr).at(4).read_u32()`.
+	///
+	/// ## Panics
+	/// If the position exceeds `N`.
+	#[track_caller]
+	pub fn at(self, position: usize) -> Self {
+		self.try_at(position).expect("failed to seek")
+	}
+
+}
+
+impl<const N: usize> PartialEq for BytesArray<N> {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+
+impl<const N: usize> Eq for BytesArray<N> {}
+
+impl<const N: usize> PartialOrd for BytesArray<N> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<const N: usize> Ord for BytesArray<N> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+impl<const N: usize> Hash for BytesArray<N> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state)
+	}
 }
 
 impl<const N: usize> BytesRead for BytesArray<N> {
@@ -115,6 +165,12 @@ impl<const N: usize> io::Write for BytesArray<N> {
 		io::Write::write(&mut self.inner, buf)
 	}
 
+	/// Writes the entire buffer at once, if it doesn't fit nothing
+	/// is written and an error with the kind `WriteZero` is returned.
+	fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+		io::Write::write_all(&mut self.inner, buf)
+	}
+
 	fn flush(&mut self) -> io::Result<()> {
 		Ok(())
 	}
@@ -139,6 +195,32 @@ impl<const N: usize> io::Seek for BytesArray<N> {
 	}
 }
 
+impl<const N: usize> Index<usize> for BytesArray<N> {
+	type Output = u8;
+
+	/// Returns the byte at the absolute `index`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `index` is out of bounds.
+	#[inline]
+	fn index(&self, index: usize) -> &u8 {
+		&self.as_slice()[index]
+	}
+}
+
+impl<const N: usize> Index<Range<usize>> for BytesArray<N> {
+	type Output = [u8];
+
+	/// Returns the bytes in the absolute `range`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `range` is out of bounds.
+	#[inline]
+	fn index(&self, range: Range<usize>) -> &[u8] {
+		&self.as_slice()[range]
+	}
+}
+
 impl<const N: usize> From<[u8; N]> for BytesArray<N> {
 	fn from(b: [u8; N]) -> Self {
 		Self::new(0, b)
@@ -157,6 +239,25 @@ mod tests {
 	use super::*;
 	use crate::BytesRead;
 
+	#[test]
+	fn written_bytes() {
+		let mut bytes = BytesArray::from([0u8; 4]);
+		assert_eq!(bytes.written_bytes().inner(), &[]);
+		bytes.write_u16(1);
+		assert_eq!(bytes.written_bytes().inner(), &[0, 1]);
+		assert_eq!(bytes.as_bytes().inner(), &[0, 1, 0, 0]);
+	}
+
+	#[test]
+	fn io_write_all() {
+		let mut bytes = BytesArray::from([0u8; 2]);
+		let e = io::Write::write_all(&mut bytes, &[1, 2, 3]).unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::WriteZero);
+		assert_eq!(bytes.position(), 0);
+		io::Write::write_all(&mut bytes, &[1, 2]).unwrap();
+		assert_eq!(bytes.into_array(), [1, 2]);
+	}
+
 	#[test]
 	fn write() {
 		let mut bytes = BytesArray::from([0u8; 100]);
@@ -195,6 +296,45 @@ mod tests {
 		assert_eq!(bytes.as_slice(), le);
 	}
 
+	#[test]
+	fn ord() {
+		let mut a = BytesArray::from([1, 2]);
+		a.seek(1);
+		let b = BytesArray::from([1, 2]);
+		assert_eq!(a, b);
+		assert!(BytesArray::from([1, 1]) < b);
+		assert!(BytesArray::from([2, 0]) > b);
+	}
+
+	#[test]
+	fn io_seek() {
+		use std::io::{Seek, SeekFrom};
+
+		let mut bytes = BytesArray::from([0u8; 4]);
+		bytes.write_u16(1);
+		assert_eq!(bytes.stream_position().unwrap(), 2);
+		assert_eq!(Seek::seek(&mut bytes, SeekFrom::End(-1)).unwrap(), 3);
+		assert!(Seek::seek(&mut bytes, SeekFrom::End(1)).is_err());
+		bytes.rewind().unwrap();
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.stream_position().unwrap(), 0);
+	}
+
+	#[test]
+	fn pad_to() {
+		let mut bytes = BytesArray::from([0u8; 4]);
+		bytes.write_u8(1);
+		assert!(bytes.try_pad_to(usize::MAX, 2).is_err());
+		assert_eq!(bytes.position(), 1);
+		assert!(bytes.try_pad_to(5, 2).is_err());
+		assert_eq!(bytes.position(), 1);
+		bytes.pad_to(3, 2);
+		assert_eq!(bytes.position(), 3);
+		bytes.pad_to(4, 3);
+		assert_eq!(bytes.position(), 4);
+		assert_eq!(bytes.into_array(), [1, 2, 2, 3]);
+	}
+
 	#[test]
 	fn test_empty() {
 		let mut bytes = BytesArray::from([0u8; 0]);
@@ -213,4 +353,11 @@ mod tests {
 
 		bytes.write_u8(5u8);
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn at() {
+		let mut bytes = BytesArray::from([1u8, 2, 3]).at(1);
+		assert_eq!(bytes.read_u16(), 0x0203);
+		assert!(BytesArray::from([0u8; 2]).try_at(3).is_err());
+	}
+}
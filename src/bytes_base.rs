@@ -0,0 +1,76 @@
+
+use crate::{Cursor, Bytes, BytesMut, BytesOwned, BytesArray};
+
+/// Gives access to the underlying buffer of possibly nested wrappers
+/// like `Offset` or `LimitedReader`.
+///
+/// Buffer types like `Cursor` or `Bytes` return themselves.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{Bytes, BytesBase, BytesRead, LimitedReader, Offset};
+/// let bytes = Bytes::from_array(&[1u8, 2, 3]);
+/// let mut nested = Offset::new(LimitedReader::new(bytes, 2), 1);
+/// nested.read_u8();
+/// assert_eq!(nested.base().inner(), &[1, 2, 3]);
+/// ```
+pub trait BytesBase {
+	/// The innermost type.
+	type Base;
+
+	/// Returns the innermost value as a reference.
+	fn base(&self) -> &Self::Base;
+
+	/// Returns the innermost value as a mutable reference.
+	///
+	/// Updating the position may break the invariants of the wrappers.
+	fn base_mut(&mut self) -> &mut Self::Base;
+}
+
+macro_rules! base_self_impl {
+	($($type:ty),*) => ($(
+		impl BytesBase for $type {
+			type Base = Self;
+
+			#[inline]
+			fn base(&self) -> &Self {
+				self
+			}
+
+			#[inline]
+			fn base_mut(&mut self) -> &mut Self {
+				self
+			}
+		}
+	)*)
+}
+
+base_self_impl!(Bytes<'_>, BytesMut<'_>, BytesOwned);
+
+impl<T> BytesBase for Cursor<T> {
+	type Base = Self;
+
+	#[inline]
+	fn base(&self) -> &Self {
+		self
+	}
+
+	#[inline]
+	fn base_mut(&mut self) -> &mut Self {
+		self
+	}
+}
+
+impl<const N: usize> BytesBase for BytesArray<N> {
+	type Base = Self;
+
+	#[inline]
+	fn base(&self) -> &Self {
+		self
+	}
+
+	#[inline]
+	fn base_mut(&mut self) -> &mut Self {
+		self
+	}
+}
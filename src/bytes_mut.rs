@@ -7,6 +7,7 @@ use crate::{
 };
 
 use std::io;
+use std::ops::{Index, Range};
 
 /// A mutable slice wrapper that implements BytesWrite
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -30,6 +31,73 @@ impl<'a> BytesMut<'a> {
 		cursor.seek(position);
 		Self { inner: cursor }
 	}
+
+	/// Creates a BytesMut over an array with the position `0`.
+	pub fn from_array<const N: usize>(arr: &'a mut [u8; N]) -> Self {
+		Self::from(&mut arr[..])
+	}
+
+	/// Returns the inner slice.
+	pub fn inner(&self) -> &[u8] {
+		self.inner.inner()
+	}
+
+	/// Sets the position, for example `BytesMut::from(slice).at(4).write_u32(1)`.
+	///
+	/// ## Fails
+	/// If the position exceeds the slice length.
+	pub fn try_at(mut self, position: usize) -> Result<Self, SeekError> {
+		self.try_seek(position)?;
+		Ok(self)
+	}
+
+	/// Sets the position, for example `BytesMut::from(slice).at(4).write_u32(1)`.
+	///
+	/// ## Panics
+	/// If the position exceeds the slice length.
+	#[track_caller]
+	pub fn at(self, position: usize) -> Self {
+		self.try_at(position).expect("failed to seek")
+	}
+
+	/// Returns the bytes from the start up to the internal position.
+	///
+	/// This reflects the position and not the full slice length, so
+	/// seeking changes what gets returned.
+	pub fn written(&self) -> &[u8] {
+		&self.inner.as_slice()[..self.inner.position()]
+	}
+
+	/// Returns the inner slice with the original reference.
+	pub fn into_slice(self) -> &'a mut [u8] {
+		self.inner.into_inner()
+	}
+
+	/// Writes a slice and returns the region it was written to with the
+	/// original lifetime, for example to fill in a placeholder later.
+	///
+	/// Because the region can't be borrowed twice, the buffer gets split.
+	/// The returned `BytesMut` only covers the bytes after the written
+	/// region and starts at the position `0`.
+	///
+	/// ## Fails
+	/// If there aren't enough remaining bytes left, in which case `self`
+	/// gets returned unchanged.
+	pub fn write_ref(
+		self,
+		slice: &[u8]
+	) -> Result<(&'a mut [u8], BytesMut<'a>), Self> {
+		let pos = self.inner.position();
+		if self.inner.inner().len() - pos < slice.len() {
+			return Err(self)
+		}
+
+		let (written, rest) = self.into_slice()[pos..]
+			.split_at_mut(slice.len());
+		written.copy_from_slice(slice);
+
+		Ok((written, BytesMut::from(rest)))
+	}
 }
 
 impl BytesRead for BytesMut<'_> {
@@ -84,6 +152,12 @@ impl io::Write for BytesMut<'_> {
 		io::Write::write(&mut self.inner, buf)
 	}
 
+	/// Writes the entire buffer at once, if it doesn't fit nothing
+	/// is written and an error with the kind `WriteZero` is returned.
+	fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+		io::Write::write_all(&mut self.inner, buf)
+	}
+
 	fn flush(&mut self) -> io::Result<()> {
 		Ok(())
 	}
@@ -110,6 +184,32 @@ impl io::Seek for BytesMut<'_> {
 	}
 }
 
+impl Index<usize> for BytesMut<'_> {
+	type Output = u8;
+
+	/// Returns the byte at the absolute `index`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `index` is out of bounds.
+	#[inline]
+	fn index(&self, index: usize) -> &u8 {
+		&self.as_slice()[index]
+	}
+}
+
+impl Index<Range<usize>> for BytesMut<'_> {
+	type Output = [u8];
+
+	/// Returns the bytes in the absolute `range`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `range` is out of bounds.
+	#[inline]
+	fn index(&self, range: Range<usize>) -> &[u8] {
+		&self.as_slice()[range]
+	}
+}
+
 impl<'a> From<&'a mut [u8]> for BytesMut<'a> {
 	fn from(s: &'a mut [u8]) -> Self {
 		Self::new(0, s)
@@ -123,6 +223,55 @@ mod tests {
 	use super::*;
 	use crate::BytesRead;
 
+	#[test]
+	fn write_ref() {
+		let mut buf = [0u8; 5];
+		let mut bytes = BytesMut::from(&mut buf[..]);
+		bytes.write_u8(1);
+		let (placeholder, mut rest) = bytes.write_ref(&[0, 0]).unwrap();
+		assert_eq!(rest.position(), 0);
+		assert_eq!(rest.len(), 2);
+		rest.write_u8(4);
+		let rest = rest.write_ref(&[0, 0]).unwrap_err();
+		assert_eq!(rest.position(), 1);
+		assert_eq!(rest.len(), 2);
+		placeholder.copy_from_slice(&[2, 3]);
+		assert_eq!(buf, [1, 2, 3, 4, 0]);
+	}
+
+	#[test]
+	fn write_partial() {
+		let mut buf = [0u8; 3];
+		let mut bytes = BytesMut::from(&mut buf[..]);
+		assert_eq!(bytes.write_partial(&[1, 2]), 2);
+		assert_eq!(bytes.write_partial(&[3, 4]), 1);
+		assert_eq!(bytes.write_partial(&[5]), 0);
+		assert_eq!(bytes.position(), 3);
+		assert_eq!(buf, [1, 2, 3]);
+	}
+
+	#[test]
+	fn io_write_all() {
+		let mut buf = [0u8; 3];
+		let mut bytes = BytesMut::from(&mut buf[..]);
+		io::Write::write_all(&mut bytes, &[1]).unwrap();
+		let e = io::Write::write_all(&mut bytes, &[2, 3, 4]).unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::WriteZero);
+		assert_eq!(bytes.position(), 1);
+		assert_eq!(buf, [1, 0, 0]);
+	}
+
+	#[test]
+	fn write_vectored() {
+		let mut buf = [0u8; 5];
+		let mut bytes = BytesMut::from(&mut buf[..]);
+		bytes.write_vectored(&[&[1, 2], &[], &[3]]);
+		assert!(bytes.try_write_vectored(&[&[4], &[5, 6]]).is_err());
+		assert_eq!(bytes.position(), 3);
+		bytes.write_vectored(&[&[4], &[5]]);
+		assert_eq!(buf, [1, 2, 3, 4, 5]);
+	}
+
 	#[test]
 	fn write() {
 
@@ -164,6 +313,64 @@ mod tests {
 		assert_eq!(bytes.as_slice(), le);
 	}
 
+	#[test]
+	fn write_run() {
+		let mut bytes = [0u8; 5];
+		let mut bytes = BytesMut::from(&mut bytes[..]);
+		bytes.write_run(7, 3);
+		assert_eq!(bytes.as_slice(), &[3, 7, 7, 7, 0]);
+		assert!(bytes.try_write_run(7, 1).is_err());
+		assert_eq!(bytes.position(), 4);
+		bytes.write_run(7, 0);
+		assert_eq!(bytes.as_slice(), &[3, 7, 7, 7, 0]);
+	}
+
+	#[test]
+	fn into_slice() {
+		let mut bytes = [0u8; 3];
+		let mut bytes = BytesMut::from(&mut bytes[..]);
+		bytes.write_u16(258);
+		assert_eq!(bytes.inner(), &[1, 2, 0]);
+		let slice = bytes.into_slice();
+		slice[2] = 3;
+		assert_eq!(slice, &[1, 2, 3]);
+	}
+
+	#[test]
+	fn from_array() {
+		let mut arr = [0u8; 3];
+		let mut bytes = BytesMut::from_array(&mut arr);
+		assert_eq!(bytes.len(), 3);
+		bytes.write_u8(1);
+		assert_eq!(arr, [1, 0, 0]);
+	}
+
+	#[test]
+	fn written() {
+		let mut bytes = [0u8; 4];
+		let mut bytes = BytesMut::from_array(&mut bytes);
+		assert_eq!(bytes.written(), &[]);
+		bytes.write_u16(1);
+		assert_eq!(bytes.written(), &[0, 1]);
+		bytes.seek(1);
+		assert_eq!(bytes.written(), &[0]);
+	}
+
+	#[test]
+	fn io_seek() {
+		use std::io::{Seek, SeekFrom};
+
+		let mut bytes = [0u8; 4];
+		let mut bytes = BytesMut::from_array(&mut bytes);
+		bytes.write_u16(1);
+		assert_eq!(bytes.stream_position().unwrap(), 2);
+		assert_eq!(Seek::seek(&mut bytes, SeekFrom::End(-1)).unwrap(), 3);
+		assert!(Seek::seek(&mut bytes, SeekFrom::End(1)).is_err());
+		bytes.rewind().unwrap();
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.stream_position().unwrap(), 0);
+	}
+
 	#[test]
 	fn test_empty() {
 		let mut bytes = BytesMut::from(&mut [][..]);
@@ -185,4 +392,33 @@ mod tests {
 		bytes.write_u8(5u8);
 	}
 
-}
\ No newline at end of file
+	#[test]
+	fn at() {
+		let mut buf = [0u8; 4];
+		BytesMut::from_array(&mut buf).at(2).write_u16(1);
+		assert_eq!(buf, [0, 0, 0, 1]);
+		assert!(BytesMut::from_array(&mut buf).try_at(5).is_err());
+	}
+
+	#[test]
+	fn write_reversed() {
+		let mut buf = [0u8; 5];
+		let mut bytes = BytesMut::from_array(&mut buf);
+		bytes.write_u8(9);
+		bytes.write_reversed(&[1, 2, 3]);
+		assert!(bytes.try_write_reversed(&[4, 5]).is_err());
+		assert_eq!(bytes.position(), 4);
+		assert_eq!(buf, [9, 3, 2, 1, 0]);
+	}
+
+	#[test]
+	fn write_f32_slice() {
+		let mut buf = [0u8; 6];
+		let mut bytes = BytesMut::from_array(&mut buf);
+		bytes.write_le_f32_slice(&[1.0]);
+		assert!(bytes.try_write_f32_slice(&[1.0]).is_err());
+		assert!(bytes.try_write_f64_slice(&[]).is_ok());
+		assert_eq!(bytes.position(), 4);
+		assert_eq!(buf, [0, 0, 0x80, 0x3f, 0, 0]);
+	}
+}
@@ -1,20 +1,40 @@
 //! # Note
-//!	Internally there exists only one position
+//! Internally there exists only one position
 //! So if you read and write you should keep this in mind
 
 use crate::{
-	Bytes, Cursor,
+	Bytes, BytesMut, Cursor,
 	BytesRead, ReadError,
 	BytesWrite, WriteError,
 	BytesSeek, SeekError
 };
 
-use std::io;
+use crate::util::{io_eof, io_other};
+
+use std::{io, fmt};
+use std::ops::{Index, Range};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Gets returned by `BytesOwned::write_report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WriteReport {
+	/// `true` if the `Vec` had to allocate more space.
+	pub grew: bool,
+	/// The length after writing.
+	pub new_len: usize
+}
 
 /// A Vec wrapper that implements BytesWrite and BytesRead
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Equality, ordering and hashing only consider the content
+/// and ignore the position.
+#[derive(Debug, Clone)]
 pub struct BytesOwned {
-	inner: Cursor<Vec<u8>>
+	inner: Cursor<Vec<u8>>,
+	growth: usize
 }
 
 impl BytesOwned {
@@ -22,14 +42,56 @@ impl BytesOwned {
 	/// Creates an empty Vec.
 	pub fn new() -> Self {
 		Self {
-			inner: Cursor::new(vec![])
+			inner: Cursor::new(vec![]),
+			growth: 0
 		}
 	}
 
 	/// Creates a new Vec with the given capacity.
 	pub fn with_capacity(cap: usize) -> Self {
 		Self {
-			inner: Cursor::new(Vec::with_capacity(cap))
+			inner: Cursor::new(Vec::with_capacity(cap)),
+			growth: 0
+		}
+	}
+
+	/// Creates a new Vec with the given capacity which grows by at least
+	/// `step` bytes each time it runs out of space.
+	///
+	/// Useful if the final size is roughly known and gets reached in
+	/// many small writes, instead of doubling the capacity only `step`
+	/// more bytes get allocated. A `step` of `0` keeps the default
+	/// growth of `Vec`.
+	pub fn with_growth(cap: usize, step: usize) -> Self {
+		let mut me = Self::with_capacity(cap);
+		me.set_growth(step);
+		me
+	}
+
+	/// Sets by how many bytes the Vec grows at least when writing past
+	/// its capacity, `0` keeps the default growth of `Vec`.
+	pub fn set_growth(&mut self, step: usize) {
+		self.growth = step;
+	}
+
+	/// Returns the step set with `with_growth` or `set_growth`.
+	#[inline]
+	pub fn growth(&self) -> usize {
+		self.growth
+	}
+
+	/// Reserves the space for writing `len` bytes at the current position
+	/// in steps of `growth`.
+	fn reserve_growth(&mut self, len: usize) {
+		if self.growth == 0 {
+			return
+		}
+
+		let end = self.inner.position().saturating_add(len);
+		let vec = self.inner.inner_mut();
+		if end > vec.capacity() {
+			let needed = end - vec.len();
+			vec.reserve_exact(needed.max(self.growth));
 		}
 	}
 
@@ -37,7 +99,30 @@ impl BytesOwned {
 	pub fn new_raw(position: usize, inner: Vec<u8>) -> Self {
 		let mut cursor = Cursor::new(inner);
 		cursor.seek(position);
-		Self { inner: cursor }
+		Self { inner: cursor, growth: 0 }
+	}
+
+	/// Sets the position, for example
+	/// `BytesOwned::from(vec).at(4).read_u32()`.
+	///
+	/// Never fails since the position may be past the end, it only exists
+	/// for consistency with the other types.
+	pub fn try_at(mut self, position: usize) -> Result<Self, SeekError> {
+		self.try_seek(position)?;
+		Ok(self)
+	}
+
+	/// Sets the position, for example
+	/// `BytesOwned::from(vec).at(4).read_u32()`.
+	///
+	/// The position may be past the end, writing fills the gap with zeros.
+	///
+	/// ## Panics
+	/// Never since `try_at` can't fail, it calls `expect` only for
+	/// consistency with the other types.
+	#[track_caller]
+	pub fn at(self, position: usize) -> Self {
+		self.try_at(position).expect("failed to seek")
 	}
 
 	/// Resizes the len to `new_len` allocates some more space if needed.
@@ -48,6 +133,144 @@ impl BytesOwned {
 		}
 	}
 
+	/// Seeks back to the start so the written bytes can be read,
+	/// returning the total length.
+	pub fn finish_writing(&mut self) -> usize {
+		self.inner.seek(0);
+		self.inner.len()
+	}
+
+	/// Returns how many bytes are left to read.
+	#[inline]
+	pub fn read_len(&self) -> usize {
+		self.inner.remaining().len()
+	}
+
+	/// Returns the number of bytes that can be held without reallocating.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.inner.inner().capacity()
+	}
+
+	/// Reserves capacity for at least `additional` more bytes.
+	///
+	/// Writing many small slices grows the `Vec` step by step, reserving
+	/// the expected size up front avoids those reallocations.
+	pub fn reserve(&mut self, additional: usize) {
+		self.inner.inner_mut().reserve(additional);
+	}
+
+	/// Reserves capacity for exactly `additional` more bytes.
+	pub fn reserve_exact(&mut self, additional: usize) {
+		self.inner.inner_mut().reserve_exact(additional);
+	}
+
+	/// Shrinks the capacity as much as possible.
+	///
+	/// The length and position stay the same.
+	pub fn shrink_to_fit(&mut self) {
+		self.inner.inner_mut().shrink_to_fit();
+	}
+
+	/// Shrinks the capacity with a lower bound.
+	///
+	/// The length and position stay the same.
+	pub fn shrink_to(&mut self, min_capacity: usize) {
+		self.inner.inner_mut().shrink_to(min_capacity);
+	}
+
+	/// Writes a slice and reports if the `Vec` had to allocate
+	/// more space.
+	///
+	/// Useful to check if a previous `reserve` was big enough.
+	pub fn write_report(&mut self, slice: impl AsRef<[u8]>) -> WriteReport {
+		let cap = self.capacity();
+		self.write(slice);
+
+		WriteReport {
+			grew: self.capacity() != cap,
+			new_len: self.len()
+		}
+	}
+
+	/// Reads exactly `len` bytes from `reader` writing them at the
+	/// current position.
+	///
+	/// Bytes past the end get appended by the reader directly, this does
+	/// not zero them first. Depending on the reader they may still get
+	/// zeroed by `std`.
+	///
+	/// ## Fails
+	/// If the end position would overflow, in which case nothing changes.
+	/// If the reader fails or ends before `len` bytes were read, appended
+	/// bytes are removed but existing bytes may have been overwritten.
+	pub fn write_from_reader<R: io::Read>(
+		&mut self,
+		reader: &mut R,
+		len: usize
+	) -> io::Result<()> {
+		let pos = self.inner.position();
+		let end = pos.checked_add(len)
+			.ok_or_else(|| io_other("position overflow"))?;
+
+		self.reserve_growth(len);
+		let vec = self.inner.inner_mut();
+		let prev_len = vec.len();
+		if vec.len() < pos {
+			vec.resize(pos, 0);
+		}
+
+		let overwrite = (vec.len() - pos).min(len);
+		if let Err(e) = reader.read_exact(&mut vec[pos..][..overwrite]) {
+			vec.truncate(prev_len);
+			return Err(e)
+		}
+
+		let append = len - overwrite;
+		if append > 0 {
+			vec.reserve(append);
+			let mut reader = io::Read::take(reader, append as u64);
+			match io::Read::read_to_end(&mut reader, vec) {
+				Ok(read) if read == append => {},
+				Ok(_) => {
+					vec.truncate(prev_len);
+					return Err(io_eof("failed to fill whole buffer"))
+				},
+				Err(e) => {
+					vec.truncate(prev_len);
+					return Err(e)
+				}
+			}
+		}
+
+		self.inner.seek(end);
+		Ok(())
+	}
+
+	/// Returns the remaining mutable slice, allocating zeros until it
+	/// is at least `min` bytes long.
+	///
+	/// The position does not get updated.
+	pub fn remaining_mut_reserved(&mut self, min: usize) -> &mut [u8] {
+		self.reserve_growth(min);
+		let pos = self.inner.position();
+		let vec = self.inner.inner_mut();
+		if vec.len() < pos + min {
+			vec.resize(pos + min, 0);
+		}
+
+		&mut vec[pos..]
+	}
+
+	/// Returns a `BytesMut` over the current contents with the
+	/// position `0`.
+	///
+	/// Writing through it never grows the Vec, which is useful to fill
+	/// in a header afterwards.
+	pub fn as_bytes_mut(&mut self) -> BytesMut<'_> {
+		BytesMut::from(self.inner.inner_mut().as_mut_slice())
+	}
+
 	/// Returns the underlying Vec mutably.
 	/// 
 	/// Removing items can lead to panics while
@@ -57,12 +280,86 @@ impl BytesOwned {
 		self.inner.inner_mut()
 	}
 
+	/// Replaces the underlying Vec returning the old one, the position
+	/// gets reset to `0`.
+	///
+	/// Allows to reuse the Vecs, for example from a pool.
+	pub fn replace_inner(&mut self, vec: Vec<u8>) -> Vec<u8> {
+		self.inner.seek(0);
+		std::mem::replace(self.inner.inner_mut(), vec)
+	}
+
 	/// Returns the underlying Vec.
 	#[inline]
 	pub fn into_vec(self) -> Vec<u8> {
 		self.inner.into_inner()
 	}
 
+	/// Returns the underlying bytes as an array if the length is `N`.
+	///
+	/// ## Fails
+	/// If the length is not `N`, in which case `self` gets returned
+	/// unchanged.
+	pub fn try_into_array<const N: usize>(self) -> Result<[u8; N], Self> {
+		let position = self.inner.position();
+		let growth = self.growth;
+		self.into_vec()
+			.try_into()
+			.map_err(|vec| {
+				let mut me = Self::new_raw(position, vec);
+				me.set_growth(growth);
+				me
+			})
+	}
+
+	/// Returns the underlying bytes as an `Rc<[u8]>`.
+	///
+	/// Wrap it in a `Cursor` to keep reading from it.
+	#[inline]
+	pub fn into_rc(self) -> Rc<[u8]> {
+		self.into_vec().into()
+	}
+
+	/// Returns the underlying bytes as an `Arc<[u8]>`.
+	///
+	/// Wrap it in a `Cursor` to keep reading from it.
+	#[inline]
+	pub fn into_arc(self) -> Arc<[u8]> {
+		self.into_vec().into()
+	}
+
+}
+
+impl Default for BytesOwned {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl PartialEq for BytesOwned {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_slice() == other.as_slice()
+	}
+}
+
+impl Eq for BytesOwned {}
+
+impl PartialOrd for BytesOwned {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for BytesOwned {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_slice().cmp(other.as_slice())
+	}
+}
+
+impl Hash for BytesOwned {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_slice().hash(state)
+	}
 }
 
 impl BytesRead for BytesOwned {
@@ -125,13 +422,31 @@ impl BytesWrite for BytesOwned {
 	/// bigger than the `Vec`.
 	#[inline]
 	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
+		let slice = slice.as_ref();
+		self.reserve_growth(slice.len());
 		self.inner.try_write(slice)
 	}
 
+	/// Writes all slices, allocating the needed space only once.
+	#[inline]
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		let len = slices.iter()
+			.fold(0usize, |len, s| len.saturating_add(s.len()));
+		self.reserve_growth(len);
+		self.inner.try_write_vectored(slices)
+	}
+
+	#[inline]
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		self.reserve_growth(slice.len());
+		self.inner.write_partial(slice)
+	}
+
 }
 
 impl io::Write for BytesOwned {
 	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.reserve_growth(buf.len());
 		io::Write::write(&mut self.inner, buf)
 	}
 
@@ -140,25 +455,63 @@ impl io::Write for BytesOwned {
 	}
 }
 
+impl fmt::Write for BytesOwned {
+	/// Writes the string as UTF-8 at the current position.
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.reserve_growth(s.len());
+		fmt::Write::write_str(&mut self.inner, s)
+	}
+}
+
 impl BytesSeek for BytesOwned {
 	/// Returns the internal position.
 	fn position(&self) -> usize {
 		self.inner.position()
 	}
 
-	/// Sets the internal position, allocating more space
-	/// if the position is bigger than the `Vec`.
+	/// Sets the internal position.
+	///
+	/// The position may be past the end of the `Vec`, the gap only
+	/// gets filled with zeros once something is written.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
 		self.inner.try_seek(pos)
 	}
 }
 
 impl io::Seek for BytesOwned {
+	/// Seeks to an offset, seeking past the end is allowed and does
+	/// not allocate until something gets written.
 	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
 		io::Seek::seek(&mut self.inner, pos)
 	}
 }
 
+impl Index<usize> for BytesOwned {
+	type Output = u8;
+
+	/// Returns the byte at the absolute `index`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `index` is out of bounds.
+	#[inline]
+	fn index(&self, index: usize) -> &u8 {
+		&self.as_slice()[index]
+	}
+}
+
+impl Index<Range<usize>> for BytesOwned {
+	type Output = [u8];
+
+	/// Returns the bytes in the absolute `range`, ignoring the position.
+	///
+	/// ## Panics
+	/// If `range` is out of bounds.
+	#[inline]
+	fn index(&self, range: Range<usize>) -> &[u8] {
+		&self.as_slice()[range]
+	}
+}
+
 impl From<Vec<u8>> for BytesOwned {
 	fn from(b: Vec<u8>) -> Self {
 		Self::new_raw(0, b)
@@ -202,13 +555,15 @@ mod tests {
 		bytes.seek(20);
 		assert_eq!(bytes.len(), 23);
 
-		// seek
+		// seeking past the end does not resize
 		bytes.seek(99);
-		assert_eq!(bytes.len(), 99);
-		// should now write to the 99 byte // this is the last byte
-		// this will resize
+		assert_eq!(bytes.len(), 23);
+		assert_eq!(bytes.position(), 99);
+		// should now write to the 99 byte
+		// this will resize and fill the gap with zeros
 		bytes.write_u8(5u8);
 		assert_eq!(bytes.as_mut()[99], 5u8);
+		assert_eq!(&bytes.as_mut()[23..99], &[0u8; 76][..]);
 		assert_eq!(bytes.len(), 100);
 
 	}
@@ -239,9 +594,450 @@ mod tests {
 		bytes.seek(254);
 		assert_eq!(255u8, bytes.read_u8());
 
-		bytes.seek(256);// should have allocated one byte
-		bytes.seek(255);
-		assert_eq!(0u8, bytes.read_u8());
+		bytes.seek(256);// does not allocate
+		assert_eq!(bytes.len(), 255);
+		assert!(bytes.try_read_u8().is_err());
+		assert_eq!(bytes.position(), 256);
+	}
+
+	#[test]
+	fn write_run() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_run(1, 2);
+		bytes.write_run(9, 255);
+		assert_eq!(bytes.len(), 3 + 256);
+		assert_eq!(&bytes.as_slice()[..4], &[2, 1, 1, 255]);
+		assert!(bytes.as_slice()[4..].iter().all(|b| *b == 9));
+	}
+
+	#[test]
+	fn slice_from() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3, 4]);
+		bytes.seek(3);
+		let a = bytes.slice_from(0, 2).unwrap();
+		let b = bytes.slice_from(2, 2).unwrap();
+		assert_eq!(a, &[1, 2]);
+		assert_eq!(b, &[3, 4]);
+		assert_eq!(bytes.slice_from(4, 0).unwrap(), &[]);
+		assert!(bytes.slice_from(3, 2).is_none());
+		assert!(bytes.slice_from(5, 0).is_none());
+		assert_eq!(bytes.position(), 3);
+	}
+
+	#[test]
+	fn reserve() {
+		let mut bytes = BytesOwned::new();
+		bytes.reserve(1000);
+		let cap = bytes.capacity();
+		assert!(cap >= 1000);
+		for i in 0..1000 {
+			bytes.write_u8(i as u8);
+		}
+		assert_eq!(bytes.capacity(), cap);
+
+		bytes.reserve_exact(10);
+		assert!(bytes.capacity() >= 1010);
+	}
+
+	#[test]
+	fn fixed_i64() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_fixed_i64(12.34, 2);
+		bytes.write_fixed_i64(-0.5, 3);
+		bytes.seek(0);
+		assert_eq!(bytes.read_i64(), 1234);
+		assert_eq!(bytes.read_i64(), -500);
+
+		bytes.seek(0);
+		assert_eq!(bytes.read_fixed_i64(2), 12.34);
+		assert_eq!(bytes.read_fixed_i64(3), -0.5);
+		assert!(bytes.try_read_fixed_i64(0).is_err());
+
+		// nothing gets written or read for values which can't be scaled
+		bytes.seek(0);
+		assert!(bytes.try_write_fixed_i64(f64::NAN, 0).is_err());
+		assert!(bytes.try_write_fixed_i64(f64::INFINITY, 0).is_err());
+		assert!(bytes.try_write_fixed_i64(1.0, 19).is_err());
+		assert!(bytes.try_write_fixed_i64(-1.0, 19).is_err());
+		assert!(bytes.try_write_fixed_i64(1.0, u32::MAX).is_err());
+		assert!(bytes.try_read_fixed_i64(u32::MAX).is_err());
+		assert_eq!(bytes.position(), 0);
+
+		bytes.write_fixed_i64(-1.0, 18);
+		bytes.seek(0);
+		assert_eq!(bytes.read_i64(), -1_000_000_000_000_000_000);
+	}
+
+	#[test]
+	fn remaining_mut_reserved() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3]);
+		bytes.seek(2);
+		bytes.remaining_mut_reserved(4).copy_from_slice(&[4, 5, 6, 7]);
+		assert_eq!(bytes.as_slice(), &[1, 2, 4, 5, 6, 7]);
+		assert_eq!(bytes.position(), 2);
+
+		// enough space already allocated
+		assert_eq!(bytes.remaining_mut_reserved(1).len(), 4);
+	}
+
+	#[test]
+	fn ord() {
+		use std::collections::BTreeSet;
+
+		let mut a = BytesOwned::from(vec![1, 2]);
+		a.seek(1);
+		let b = BytesOwned::from(vec![1, 2]);
+		assert_eq!(a, b);
+		assert!(BytesOwned::from(vec![1]) < b);
+		assert!(BytesOwned::from(vec![2]) > b);
+
+		let set: BTreeSet<_> = vec![a, b, BytesOwned::new()].into_iter().collect();
+		assert_eq!(set.len(), 2);
+		assert_eq!(set.iter().next().unwrap().len(), 0);
+	}
+
+	#[test]
+	fn into_rc() {
+		let bytes = BytesOwned::from(vec![1, 2, 3]);
+		let rc = bytes.into_rc();
+		let mut cursor = Cursor::new(rc.clone());
+		assert_eq!(cursor.read_u8(), 1);
+		cursor.seek(1);
+		assert_eq!(cursor.read_u16(), 515);
+		assert!(cursor.try_seek(4).is_err());
+		drop(rc);
+		cursor.seek(0);
+		assert_eq!(cursor.remaining(), &[1, 2, 3]);
+
+		let arc = BytesOwned::from(vec![1, 2]).into_arc();
+		let mut cursor = Cursor::new(arc);
+		cursor.seek(2);
+		assert!(cursor.try_read_u8().is_err());
+	}
+
+	#[test]
+	fn float_bits() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_f32_bits(1.5f32.to_bits());
+		bytes.write_f64_bits(0x7ff8_0000_0000_0001);
+		// signaling NaN
+		bytes.write_f32_bits(0x7f80_0001);
+		bytes.write_f64_bits(0x7ff0_0000_0000_0001);
+		bytes.seek(0);
+
+		assert_eq!(bytes.read_f32_bits(), 1.5f32.to_bits());
+		assert_eq!(bytes.read_f64_bits(), 0x7ff8_0000_0000_0001);
+
+		bytes.seek(0);
+		assert_eq!(bytes.try_read_f32_nan_checked(), Ok(1.5));
+		assert!(bytes.try_read_f64_nan_checked().unwrap().is_nan());
+		assert!(bytes.try_read_f32_nan_checked().is_err());
+		assert_eq!(bytes.position(), 12);
+		bytes.seek(16);
+		assert!(bytes.try_read_f64_nan_checked().is_err());
+		assert_eq!(bytes.position(), 16);
+	}
+
+	#[test]
+	fn write_report() {
+		let mut bytes = BytesOwned::with_capacity(4);
+		let report = bytes.write_report([1, 2, 3]);
+		assert_eq!(report, WriteReport { grew: false, new_len: 3 });
+		let report = bytes.write_report([4, 5]);
+		assert_eq!(report, WriteReport { grew: true, new_len: 5 });
+		bytes.seek(0);
+		let report = bytes.write_report([1]);
+		assert_eq!(report, WriteReport { grew: false, new_len: 5 });
+	}
+
+	#[test]
+	fn io_seek() {
+		use std::io::{Seek, SeekFrom};
+
+		let mut bytes = BytesOwned::new();
+		bytes.write_u16(1);
+		assert_eq!(bytes.stream_position().unwrap(), 2);
+		assert_eq!(Seek::seek(&mut bytes, SeekFrom::End(-1)).unwrap(), 1);
+		assert!(Seek::seek(&mut bytes, SeekFrom::End(-3)).is_err());
+		// seeking past the end does not allocate
+		assert_eq!(Seek::seek(&mut bytes, SeekFrom::End(2)).unwrap(), 4);
+		assert_eq!(bytes.as_slice(), &[0, 1]);
+		assert_eq!(bytes.remaining(), &[]);
+		assert!(bytes.try_read_u8().is_err());
+		assert_eq!(bytes.remaining_mut(), &[]);
+		// until something gets written
+		bytes.write_u8(2);
+		assert_eq!(bytes.as_slice(), &[0, 1, 0, 0, 2]);
+
+		Seek::seek(&mut bytes, SeekFrom::Current(1)).unwrap();
+		let mut reader = &[3u8][..];
+		bytes.write_from_reader(&mut reader, 1).unwrap();
+		assert_eq!(bytes.as_slice(), &[0, 1, 0, 0, 2, 0, 3]);
+		bytes.rewind().unwrap();
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.stream_position().unwrap(), 0);
+	}
+
+	#[test]
+	fn var_u64() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_var_u64(0);
+		bytes.write_var_u64(300);
+		bytes.write_var_u64(u64::MAX);
+		assert_eq!(&bytes.as_slice()[..3], &[0, 0xac, 0x02]);
+		assert_eq!(bytes.len(), 1 + 2 + 10);
+
+		bytes.seek(0);
+		assert_eq!(bytes.read_var_u64(), 0);
+		assert_eq!(bytes.read_var_u64(), 300);
+		assert_eq!(bytes.read_var_u64(), u64::MAX);
+		assert!(bytes.try_read_var_u64().is_err());
+
+		// too long
+		let mut bytes = BytesOwned::from(vec![0xff; 11]);
+		assert!(bytes.try_read_var_u64().is_err());
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn var_prefixed() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_var_prefixed(b"hello");
+		bytes.write_var_prefixed([]);
+		assert_eq!(bytes.as_slice(), b"\x05hello\x00");
+
+		bytes.seek(0);
+		assert_eq!(bytes.read_var_prefixed(), b"hello");
+		assert_eq!(bytes.read_var_prefixed(), b"");
+
+		let mut bytes = BytesOwned::from(vec![3, 1, 2]);
+		assert!(bytes.try_read_var_prefixed().is_err());
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn write_from_reader() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3]);
+		bytes.seek(1);
+		let mut reader = &[4u8, 5, 6, 7][..];
+		bytes.write_from_reader(&mut reader, 3).unwrap();
+		assert_eq!(bytes.as_slice(), &[1, 4, 5, 6]);
+		assert_eq!(bytes.position(), 4);
+
+		let err = bytes.write_from_reader(&mut reader, 2).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+		assert_eq!(bytes.as_slice(), &[1, 4, 5, 6]);
+		assert_eq!(bytes.position(), 4);
+		bytes.seek(usize::MAX);
+		let err = bytes.write_from_reader(&mut reader, 1).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::Other);
+		assert_eq!(bytes.as_slice(), &[1, 4, 5, 6]);
+		assert_eq!(bytes.position(), usize::MAX);
+	}
+
+	#[test]
+	fn finish_writing() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_u16(1);
+		bytes.write_u8(2);
+		assert_eq!(bytes.read_len(), 0);
+		assert_eq!(bytes.finish_writing(), 3);
+		assert_eq!(bytes.read_len(), 3);
+		assert_eq!(bytes.read_u16(), 1);
+		assert_eq!(bytes.read_len(), 1);
+	}
+
+	#[test]
+	fn pad_to() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_u16(1);
+		bytes.pad_to(5, 0xff);
+		assert_eq!(bytes.as_slice(), &[0, 1, 0xff, 0xff, 0xff]);
+		assert_eq!(bytes.position(), 5);
+		bytes.pad_to(3, 0);
+		assert_eq!(bytes.len(), 5);
+
+		// the existing bytes are kept
+		let mut bytes = BytesOwned::from(vec![1; 10]);
+		bytes.seek(2);
+		bytes.pad_to(8, 0);
+		assert_eq!(bytes.as_slice(), &[1; 10]);
+		assert_eq!(bytes.position(), 8);
+		bytes.pad_to(140, 0);
+		assert_eq!(bytes.as_slice()[10..], [0; 130]);
+		assert_eq!(bytes.position(), 140);
+	}
+
+	#[test]
+	fn skip_within() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3]);
+		bytes.skip_within(2);
+		assert_eq!(bytes.position(), 2);
+		assert!(bytes.try_skip_within(2).is_err());
+		assert_eq!(bytes.position(), 2);
+		assert_eq!(bytes.len(), 3);
+	}
+
+	#[test]
+	fn option() {
+		let mut bytes = BytesOwned::new();
+		bytes.try_write_option(Some(5u16), |b, v| b.try_write_u16(v)).unwrap();
+		bytes.try_write_option(None::<u16>, |b, v| b.try_write_u16(v))
+			.unwrap();
+		bytes.write_u8(2);
+		assert_eq!(bytes.as_slice(), &[1, 0, 5, 0, 2]);
+
+		bytes.seek(0);
+		let read = |b: &mut BytesOwned| b.try_read_u16();
+		assert_eq!(bytes.try_read_option(read).unwrap(), Some(5));
+		assert_eq!(bytes.try_read_option(read).unwrap(), None);
+		assert!(bytes.try_read_option(read).is_err());
+		assert_eq!(bytes.position(), 5);
+	}
+
+	#[test]
+	fn typed() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_typed(&1u16);
+		bytes.write_typed(&-2i32);
+		bytes.write_typed(&1.5f32);
+		assert_eq!(bytes.len(), 10);
+
+		bytes.seek(0);
+		assert_eq!(bytes.read_typed::<u16>(), 1);
+		assert_eq!(bytes.read_typed::<i32>(), -2);
+		assert_eq!(bytes.read_typed::<f32>(), 1.5);
+		assert!(bytes.try_read_typed::<u8>().is_err());
+	}
+
+	#[test]
+	fn nibbles_bcd() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_nibbles(0xa, 0x5);
+		bytes.write_bcd_u8(42);
+		bytes.write_bcd_u8(7);
+		assert!(bytes.try_write_bcd_u8(100).is_err());
+		assert!(bytes.try_write_nibbles(16, 0).is_err());
+		assert_eq!(bytes.as_slice(), &[0xa5, 0x42, 0x07]);
+
+		bytes.seek(0);
+		assert!(bytes.try_read_bcd_u8().is_err());
+		assert_eq!(bytes.position(), 0);
+		assert_eq!(bytes.read_nibbles(), (0xa, 0x5));
+		assert_eq!(bytes.read_bcd_u8(), 42);
+		assert_eq!(bytes.read_bcd_u8(), 7);
+		assert!(bytes.try_read_nibbles().is_err());
+	}
+
+	#[test]
+	fn write_vectored() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_u8(9);
+		bytes.seek(2);
+		bytes.write_vectored(&[&[1, 2], &[3]]);
+		assert_eq!(bytes.as_slice(), &[9, 0, 1, 2, 3]);
+		bytes.seek(4);
+		bytes.write_vectored(&[&[4], &[5]]);
+		assert_eq!(bytes.as_slice(), &[9, 0, 1, 2, 4, 5]);
+	}
+
+	#[test]
+	fn read_remaining_past_end() {
+		let mut bytes = BytesOwned::from(vec![1]);
+		bytes.seek(3);
+		assert_eq!(bytes.read_remaining(), &[]);
+		assert_eq!(bytes.position(), 3);
+	}
+
+	#[test]
+	fn write_partial() {
+		let mut bytes = BytesOwned::new();
+		assert_eq!(bytes.write_partial(&[1, 2]), 2);
+		assert_eq!(bytes.as_slice(), &[1, 2]);
+	}
+
+	#[test]
+	fn guid_le() {
+		// {00112233-4455-6677-8899-AABBCCDDEEFF}
+		let canonical = [
+			0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+			0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+		];
+		let mut bytes = BytesOwned::new();
+		bytes.write_guid_le(canonical);
+		assert_eq!(bytes.as_slice(), &[
+			0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66,
+			0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+		]);
+
+		bytes.seek(0);
+		assert_eq!(bytes.read_guid_le(), canonical);
+		bytes.seek(1);
+		assert!(bytes.try_read_guid_le().is_err());
+		assert_eq!(bytes.position(), 1);
+	}
+
+	#[test]
+	fn u32_vec_endian() {
+		use crate::Endian;
+
+		let mut bytes = BytesOwned::new();
+		bytes.write_u32_slice_endian(&[1, 2], Endian::Little);
+		assert_eq!(bytes.as_slice(), &[1, 0, 0, 0, 2, 0, 0, 0]);
+
+		bytes.seek(0);
+		assert_eq!(
+			bytes.read_u32_vec_endian(2, Endian::Big),
+			[0x01000000, 0x02000000]
+		);
+		bytes.seek(0);
+		assert_eq!(bytes.read_u32_vec_endian(2, Endian::Little), [1, 2]);
+		bytes.seek(0);
+		assert!(bytes.try_read_u32_vec_endian(3, Endian::Big).is_err());
+		assert!(bytes.try_read_u32_vec_endian(usize::MAX, Endian::Big).is_err());
+		assert_eq!(bytes.position(), 0);
+	}
+
+	#[test]
+	fn fmt_write() {
+		use std::fmt::Write;
+
+		let mut bytes = BytesOwned::new();
+		let key = "len";
+		write!(bytes, "{}: {}", key, 42).unwrap();
+		assert_eq!(bytes.as_slice(), b"len: 42");
+
+		bytes.seek(5);
+		write!(bytes, "{:04}", 7u8).unwrap();
+		assert_eq!(bytes.as_slice(), b"len: 0007");
+		assert_eq!(bytes.position(), 9);
+	}
+
+	#[test]
+	fn xor_checksum() {
+		let mut bytes = BytesOwned::new();
+		bytes.write([0x02, 0x10, 0x31, 0x03]);
+		assert_eq!(bytes.xor_checksum(0..4), 0x20);
+		assert_eq!(bytes.xor_checksum(1..3), 0x21);
+		assert!(bytes.try_xor_checksum(2..5).is_err());
+
+		bytes.write_xor_checksum_of(0..4);
+		assert!(bytes.try_write_xor_checksum_of(0..6).is_err());
+		assert_eq!(bytes.as_slice(), &[0x02, 0x10, 0x31, 0x03, 0x20]);
+		assert_eq!(bytes.xor_checksum(0..5), 0);
+	}
+
+	#[test]
+	fn shrink() {
+		let mut bytes = BytesOwned::with_capacity(100);
+		bytes.write_u32(1);
+		bytes.seek(2);
+		bytes.shrink_to(10);
+		assert!(bytes.capacity() >= 10 && bytes.capacity() < 100);
+		bytes.shrink_to_fit();
+		assert!(bytes.capacity() >= 4);
+		assert_eq!(bytes.position(), 2);
+		assert_eq!(bytes.as_slice(), &[0, 0, 0, 1]);
 	}
 
 	#[test]
@@ -268,4 +1064,253 @@ mod tests {
 		assert_eq!(bytes.as_slice(), &[2, 0, 0, 0]);
 		assert!(bytes.try_read(1).is_err());
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn at() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3]).at(5);
+		bytes.write_u8(4);
+		assert_eq!(bytes.as_slice(), &[1, 2, 3, 0, 0, 4]);
+		assert_eq!(BytesOwned::new().try_at(2).unwrap().position(), 2);
+	}
+
+	#[test]
+	fn index() {
+		let bytes = BytesOwned::from(vec![1, 2, 3]).at(3);
+		assert_eq!(bytes[2], 3);
+		assert_eq!(&bytes[0..2], &[1, 2]);
+		assert_eq!(&bytes[3..3], &[]);
+	}
+
+	#[test]
+	fn replace_inner() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_u16(1);
+
+		let old = bytes.replace_inner(Vec::with_capacity(10));
+		assert_eq!(old, [0, 1]);
+		assert_eq!(bytes.position(), 0);
+		assert!(bytes.as_slice().is_empty());
+		assert!(bytes.capacity() >= 10);
+
+		bytes.write_u8(2);
+		assert_eq!(bytes.replace_inner(old), [2]);
+		assert_eq!(bytes.read_u16(), 1);
+	}
+
+	#[test]
+	fn advance_saturating() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3]);
+		assert_eq!(bytes.advance_saturating(10), 3);
+		assert_eq!(bytes.position(), 3);
+		assert_eq!(bytes.as_slice().len(), 3);
+
+		// past the end the position stays
+		bytes.seek(5);
+		assert_eq!(bytes.advance_saturating(1), 0);
+		assert_eq!(bytes.position(), 5);
+	}
+
+	#[test]
+	fn try_into_array() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_u16(1);
+		bytes.set_growth(8);
+		let bytes = bytes.try_into_array::<3>().unwrap_err();
+		assert_eq!(bytes.as_slice(), &[0, 1]);
+		assert_eq!(bytes.position(), 2);
+		assert_eq!(bytes.growth(), 8);
+
+		assert_eq!(bytes.try_into_array::<2>().unwrap(), [0, 1]);
+	}
+
+	#[test]
+	fn filetime() {
+		use std::time::{Duration, UNIX_EPOCH};
+
+		// 2000-01-01T00:00:00Z
+		let y2k = UNIX_EPOCH + Duration::from_secs(946_684_800);
+		let mut bytes = BytesOwned::from(vec![
+			0, 64, 109, 37, 235, 83, 191, 1,
+			0, 128, 62, 213, 222, 177, 157, 1
+		]);
+		assert_eq!(bytes.read_filetime(), y2k);
+		assert_eq!(bytes.read_filetime(), UNIX_EPOCH);
+		assert!(bytes.try_read_filetime().is_err());
+
+		let mut bytes = BytesOwned::new();
+		bytes.write_filetime(y2k + Duration::from_nanos(150));
+		bytes.seek(0);
+		assert_eq!(bytes.read_filetime(), y2k + Duration::from_nanos(100));
+
+		let before_1601 = UNIX_EPOCH - Duration::from_secs(11_644_473_601);
+		assert!(bytes.try_write_filetime(before_1601).is_err());
+		assert_eq!(bytes.as_slice().len(), 8);
+	}
+
+	#[test]
+	fn unix_millis() {
+		use std::time::{Duration, UNIX_EPOCH};
+
+		let mut bytes = BytesOwned::from(vec![
+			0, 0, 0, 220, 106, 207, 172, 123,
+			255, 255, 255, 255, 255, 255, 250, 36
+		]);
+		let time = UNIX_EPOCH + Duration::from_millis(946_684_800_123);
+		assert_eq!(bytes.read_unix_millis(), time);
+		let before = UNIX_EPOCH - Duration::from_millis(1500);
+		assert_eq!(bytes.read_unix_millis(), before);
+
+		let mut bytes = BytesOwned::new();
+		bytes.write_unix_millis(time + Duration::from_micros(999));
+		// rounds down before the epoch as well
+		bytes.write_unix_millis(before - Duration::from_micros(1));
+		bytes.seek(0);
+		assert_eq!(bytes.read_unix_millis(), time);
+		assert_eq!(bytes.read_unix_millis(), before - Duration::from_millis(1));
+	}
+
+	#[test]
+	fn try_seek_from() {
+		use std::io::SeekFrom;
+
+		let mut bytes = BytesOwned::from(vec![1, 2]);
+		assert_eq!(bytes.try_seek_from(SeekFrom::End(2)).unwrap(), 4);
+		assert_eq!(bytes.as_slice().len(), 2);
+		assert!(bytes.try_seek_from(SeekFrom::End(-3)).is_err());
+
+		// io::Seek keeps its error kinds
+		let err = io::Seek::seek(&mut bytes, SeekFrom::Current(i64::MAX))
+			.unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::Other);
+		let err = io::Seek::seek(&mut bytes, SeekFrom::End(-3)).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn write_read_reversed() {
+		let mut bytes = BytesOwned::from(vec![0, 0, 0]);
+		bytes.seek(1);
+		bytes.write_reversed(&[1, 2, 3, 4]);
+		assert_eq!(bytes.as_slice(), &[0, 4, 3, 2, 1]);
+
+		bytes.seek(1);
+		assert_eq!(bytes.read_reversed(4), [1, 2, 3, 4]);
+		assert!(bytes.try_read_reversed(1).is_err());
+	}
+
+	#[test]
+	fn as_bytes_mut() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_u16(0);
+		bytes.write(b"body");
+
+		let mut header = bytes.as_bytes_mut();
+		header.write_u16(4);
+		assert!(header.try_write([0; 5]).is_err());
+
+		assert_eq!(bytes.as_slice(), b"\x00\x04body");
+		assert_eq!(bytes.position(), 6);
+	}
+
+	#[test]
+	fn f32_f64_slices() {
+		let mut bytes = BytesOwned::new();
+		bytes.write_f32_slice(&[1.5, -2.0]);
+		bytes.write_le_f64_slice(&[0.25]);
+		assert_eq!(bytes.as_slice(), &[
+			0x3f, 0xc0, 0, 0, 0xc0, 0, 0, 0,
+			0, 0, 0, 0, 0, 0, 0xd0, 0x3f
+		]);
+
+		bytes.seek(0);
+		let mut floats = [0f32; 2];
+		bytes.read_f32_into(&mut floats);
+		assert_eq!(floats, [1.5, -2.0]);
+
+		let mut doubles = [0f64; 2];
+		assert!(bytes.try_read_le_f64_into(&mut doubles).is_err());
+		assert_eq!(doubles, [0.0; 2]);
+		bytes.read_le_f64_into(&mut doubles[..1]);
+		assert_eq!(doubles, [0.25, 0.0]);
+		assert!(bytes.at_end());
+	}
+
+
+	#[test]
+	fn with_growth() {
+		let mut bytes = BytesOwned::with_growth(100_000, 1_000);
+		assert_eq!(bytes.growth(), 1_000);
+
+		let mut reallocs = 0;
+		let mut cap = bytes.capacity();
+		for _ in 0..100_001 {
+			bytes.write_u8(1);
+			if bytes.capacity() != cap {
+				reallocs += 1;
+				cap = bytes.capacity();
+			}
+		}
+
+		// doubling would have grown to 200_000
+		assert_eq!(reallocs, 1);
+		assert!(bytes.capacity() >= 100_001 && bytes.capacity() < 150_000);
+
+		// writing past the end grows at least by the missing part
+		bytes.seek(200_000);
+		bytes.write([1, 2]);
+		assert_eq!(bytes.len(), 200_002);
+	}
+
+
+	#[test]
+	fn read_raw_and_past_end() {
+		let mut bytes = BytesOwned::from(vec![1, 2, 3]);
+		bytes.seek(1);
+		let res = bytes.try_read_raw_and(|b| {
+			b.seek(10);
+			Ok(())
+		});
+		assert!(res.is_err());
+		assert_eq!(bytes.position(), 1);
+	}
+
+
+	#[test]
+	fn f32_slice_overwrite() {
+		// overwrites in place and appends the rest
+		let mut bytes = BytesOwned::from(vec![9; 6]);
+		bytes.seek(2);
+		bytes.write_f32_slice(&[0.0, 0.0]);
+		assert_eq!(bytes.as_slice(), &[9, 9, 0, 0, 0, 0, 0, 0, 0, 0]);
+		assert_eq!(bytes.position(), 10);
+	}
+
+
+	#[test]
+	fn u32_slice_overwrite() {
+		use crate::Endian;
+
+		let mut bytes = BytesOwned::from(vec![9; 6]);
+		bytes.seek(2);
+		bytes.write_u32_slice_endian(&[1, 2], Endian::Big);
+		assert_eq!(bytes.as_slice(), &[9, 9, 0, 0, 0, 1, 0, 0, 0, 2]);
+
+		bytes.seek(0);
+		bytes.write_u32_slice_endian(&[3], Endian::Big);
+		assert_eq!(bytes.position(), 4);
+		assert_eq!(bytes.len(), 10);
+	}
+
+
+	#[test]
+	fn growth_append_paths() {
+		let mut bytes = BytesOwned::with_growth(0, 100);
+		bytes.remaining_mut_reserved(1)[0] = 1;
+		assert!(bytes.capacity() >= 100);
+
+		let mut bytes = BytesOwned::with_growth(0, 100);
+		bytes.write_from_reader(&mut &[1u8, 2][..], 2).unwrap();
+		assert_eq!(bytes.as_slice(), &[1, 2]);
+		assert!(bytes.capacity() >= 100);
+	}
+}
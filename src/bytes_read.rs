@@ -1,5 +1,15 @@
 
+use crate::{BytesOwned, BytesSeek, SplitIter, Endian, FromBytes};
+use crate::util::{
+	decode_var_u64, swap_guid_fields, xor_checksum, split_kv_map_u32,
+	filetime_to_system_time, unix_millis_to_system_time
+};
+
 use std::fmt;
+use std::iter::{Copied, Zip};
+use std::ops::{Range, RangeFrom};
+use std::slice;
+use std::time::SystemTime;
 
 macro_rules! read_fn {
 	($name:ident, $try_name:ident, $type:ident, $num:expr) => (
@@ -77,6 +87,203 @@ macro_rules! read_le_fn {
 	}
 }
 
+macro_rules! read_enum_fn {
+	($name:ident, $try_name:ident, $type:ident, $num:expr) => (
+		read_enum_fn!(
+			$name, $try_name,
+			$type, $num, stringify!($type), stringify!($num)
+		);
+	);
+	($name:ident, $try_name:ident, $type:ident, $num:expr,
+	$type_str:expr, $num_str:expr) => {
+		#[inline]
+		#[doc = "Try to read "]
+		#[doc = $num_str]
+		#[doc = " bytes in big-endian converting them into an `"]
+		#[doc = $type_str]
+		#[doc = "` and then into `E` via `TryFrom`."]
+		///
+		/// If the conversion fails nothing is read.
+		fn $try_name<E>(&mut self) -> Result<E, ReadError>
+		where
+			E: TryFrom<$type>,
+			Self: Sized
+		{
+			let num = self.peek($num)
+				.and_then(|b| b.try_into().ok())
+				.map($type::from_be_bytes)
+				.ok_or(ReadError)?;
+			let e = E::try_from(num).map_err(|_| ReadError)?;
+			self.try_read($num)?;
+
+			Ok(e)
+		}
+
+		#[inline]
+		#[track_caller]
+		#[doc = "Reads "]
+		#[doc = $num_str]
+		#[doc = " bytes in big-endian converting them into an `"]
+		#[doc = $type_str]
+		#[doc = "` and then into `E` via `TryFrom`."]
+		///
+		/// ## Panics
+		/// If there aren't enough bytes left or the conversion fails.
+		fn $name<E>(&mut self) -> E
+		where
+			E: TryFrom<$type>,
+			Self: Sized
+		{
+			self.$try_name().expect(concat!("failed to read enum ", $type_str))
+		}
+	}
+}
+
+macro_rules! read_str_fn {
+	($name:ident, $try_name:ident, $type:ident, $num:expr) => (
+		read_str_fn!(
+			$name, $try_name,
+			$type, $num, stringify!($type)
+		);
+	);
+	($name:ident, $try_name:ident, $type:ident, $num:expr,
+	$type_str:expr) => {
+		#[doc = "Try to read a string prefixed by its length as an `"]
+		#[doc = $type_str]
+		#[doc = "` in big-endian."]
+		///
+		/// ## Fails
+		/// If there aren't enough bytes left or the reader refuses to
+		/// read them, in which case nothing is read. Invalid UTF-8 only
+		/// gets detected after reading, so the string is still read.
+		fn $try_name(&mut self) -> Result<&str, StrReadError> {
+			let rem = self.remaining();
+			let len = rem.get(..$num)
+				.and_then(|b| b.try_into().ok())
+				.map($type::from_be_bytes)
+				.ok_or_else(|| StrReadError::Truncated {
+					needed: $num - rem.len()
+				})?;
+			// a total which doesn't fit can never be available
+			let total = usize::try_from(len).ok()
+				.and_then(|len| len.checked_add($num))
+				.unwrap_or(usize::MAX);
+			if total > rem.len() {
+				return Err(StrReadError::Truncated {
+					needed: total - rem.len()
+				})
+			}
+
+			let s = self.try_read(total)
+				.map_err(|_| StrReadError::Refused)?;
+			std::str::from_utf8(&s[$num..]).map_err(StrReadError::from)
+		}
+
+		#[track_caller]
+		#[doc = "Reads a string prefixed by its length as an `"]
+		#[doc = $type_str]
+		#[doc = "` in big-endian."]
+		///
+		/// ## Panics
+		/// If there aren't enough bytes left or the string is not valid
+		/// UTF-8.
+		fn $name(&mut self) -> &str {
+			self.$try_name().expect("failed to read str")
+		}
+	}
+}
+
+macro_rules! read_array_fn {
+	($name:ident, $try_name:ident, $type:ident, $num:expr,
+	$from_bytes:ident, $endian:expr) => (
+		read_array_fn!(
+			$name, $try_name,
+			$type, $num, $from_bytes, $endian, stringify!($type)
+		);
+	);
+	($name:ident, $try_name:ident, $type:ident, $num:expr,
+	$from_bytes:ident, $endian:expr, $type_str:expr) => {
+		#[inline]
+		#[doc = "Try to read `N` `"]
+		#[doc = $type_str]
+		#[doc = "`s in "]
+		#[doc = $endian]
+		#[doc = " into an array."]
+		///
+		/// If this fails nothing is read.
+		fn $try_name<const N: usize>(&mut self) -> Result<[$type; N], ReadError>
+		where Self: Sized {
+			let len = N.checked_mul($num).ok_or(ReadError)?;
+			let bytes = self.try_read(len)?;
+			let mut arr = [$type::default(); N];
+			for (num, b) in arr.iter_mut().zip(bytes.chunks_exact($num)) {
+				*num = $type::$from_bytes(b.try_into().unwrap());
+			}
+
+			Ok(arr)
+		}
+
+		#[inline]
+		#[track_caller]
+		#[doc = "Reads `N` `"]
+		#[doc = $type_str]
+		#[doc = "`s in "]
+		#[doc = $endian]
+		#[doc = " into an array."]
+		///
+		/// ## Panics
+		/// If there aren't enough bytes left.
+		fn $name<const N: usize>(&mut self) -> [$type; N]
+		where Self: Sized {
+			self.$try_name().expect(concat!("failed to read ", $type_str, " array"))
+		}
+	}
+}
+
+macro_rules! read_into_fn {
+	($name:ident, $try_name:ident, $type:ident, $num:expr,
+	$from_bytes:ident, $endian:expr) => (
+		read_into_fn!(
+			$name, $try_name,
+			$type, $num, $from_bytes, $endian, stringify!($type)
+		);
+	);
+	($name:ident, $try_name:ident, $type:ident, $num:expr,
+	$from_bytes:ident, $endian:expr, $type_str:expr) => {
+		#[inline]
+		#[doc = "Try to fill `out` with `"]
+		#[doc = $type_str]
+		#[doc = "`s in "]
+		#[doc = $endian]
+		#[doc = "."]
+		///
+		/// If this fails nothing is read and `out` is left unchanged.
+		fn $try_name(&mut self, out: &mut [$type]) -> Result<(), ReadError> {
+			let len = out.len().checked_mul($num).ok_or(ReadError)?;
+			let bytes = self.try_read(len)?;
+			for (num, b) in out.iter_mut().zip(bytes.chunks_exact($num)) {
+				*num = $type::$from_bytes(b.try_into().unwrap());
+			}
+
+			Ok(())
+		}
+
+		#[inline]
+		#[track_caller]
+		#[doc = "Fills `out` with `"]
+		#[doc = $type_str]
+		#[doc = "`s in "]
+		#[doc = $endian]
+		#[doc = "."]
+		///
+		/// ## Panics
+		/// If there aren't enough bytes left.
+		fn $name(&mut self, out: &mut [$type]) {
+			self.$try_name(out).expect(concat!("failed to read ", $type_str, "s"))
+		}
+	}
+}
+
 /// Get's returned when there is not enough space to read everything.
 /// If this get's returned nothing was read.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -90,6 +297,70 @@ impl fmt::Display for ReadError {
 
 impl std::error::Error for ReadError {}
 
+/// Get's returned when a length prefixed string could not be read.
+/// If this get's returned nothing was read, except for `InvalidUtf8`
+/// from the length prefixed reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrReadError {
+	/// There are not enough bytes left, `needed` more bytes are required.
+	Truncated {
+		needed: usize
+	},
+	/// The string is not valid UTF-8, the bytes before `valid_up_to`
+	/// are valid.
+	InvalidUtf8 {
+		valid_up_to: usize
+	},
+	/// There are enough bytes left but the reader refused the read,
+	/// for example a `LimitedReader` with a smaller `max_len`.
+	Refused
+}
+
+impl From<std::str::Utf8Error> for StrReadError {
+	fn from(e: std::str::Utf8Error) -> Self {
+		Self::InvalidUtf8 { valid_up_to: e.valid_up_to() }
+	}
+}
+
+impl fmt::Display for StrReadError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(self, f)
+	}
+}
+
+impl std::error::Error for StrReadError {}
+
+/// Get's returned by `BytesRead::try_read_or_need`.
+/// If this get's returned nothing was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NeedError {
+	/// There are not enough bytes left, `needed` more bytes are required.
+	Truncated {
+		needed: usize
+	},
+	/// There are enough bytes left but the reader refused the read,
+	/// for example a `LimitedReader` with a smaller `max_len`.
+	Refused
+}
+
+impl fmt::Display for NeedError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(self, f)
+	}
+}
+
+impl std::error::Error for NeedError {}
+
+/// The width of the length which follows the sentinel, see
+/// `BytesRead::try_read_extended_len_u8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtendedLen {
+	/// A `u16` in big-endian.
+	U16,
+	/// A `u32` in big-endian.
+	U32
+}
+
 /// Read bytes or numbers.
 pub trait BytesRead {
 	/// Returns the entire slice.
@@ -101,9 +372,27 @@ pub trait BytesRead {
 		self.as_slice().len()
 	}
 
+	/// Returns `true` if the entire slice is empty.
+	#[inline]
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
 	/// Returns all remaining bytes.
 	fn remaining(&self) -> &[u8];
 
+	/// Returns how many bytes are remaining.
+	#[inline]
+	fn remaining_len(&self) -> usize {
+		self.remaining().len()
+	}
+
+	/// Returns `true` if there are no bytes remaining.
+	#[inline]
+	fn at_end(&self) -> bool {
+		self.remaining().is_empty()
+	}
+
 	/// Try to read a given length of bytes.
 	/// 
 	/// ## Failes
@@ -119,6 +408,509 @@ pub trait BytesRead {
 		self.try_read(len).expect("failed to read")
 	}
 
+	/// Try to skip `len` bytes without going past the end.
+	///
+	/// Unlike `BytesSeek::try_advance` this never moves the position
+	/// past the end of growable buffers.
+	///
+	/// If this fails the position is not updated.
+	#[inline]
+	fn try_skip_within(&mut self, len: usize) -> Result<(), ReadError> {
+		self.try_read(len).map(|_| ())
+	}
+
+	/// Skips `len` bytes without going past the end.
+	///
+	/// ## Panics
+	/// If len exceeds `self.remaining().len()`.
+	#[inline]
+	#[track_caller]
+	fn skip_within(&mut self, len: usize) {
+		self.try_skip_within(len).expect("failed to skip")
+	}
+
+	/// Try to read a given length of bytes, returning how many
+	/// additional bytes would be needed if there aren't enough left.
+	///
+	/// If this fails nothing is read.
+	fn try_read_or_need(&mut self, len: usize) -> Result<&[u8], NeedError> {
+		let rem = self.remaining().len();
+		self.try_read(len).map_err(|_| match len.saturating_sub(rem) {
+			0 => NeedError::Refused,
+			needed => NeedError::Truncated { needed }
+		})
+	}
+
+	/// Try to read `len` bytes returning them in reverse order.
+	///
+	/// ## Fails
+	/// If len exceeds `self.remaining().len()`.
+	fn try_read_reversed(&mut self, len: usize) -> Result<Vec<u8>, ReadError> {
+		self.try_read(len)
+			.map(|s| s.iter().rev().copied().collect())
+	}
+
+	/// Reads `len` bytes returning them in reverse order.
+	///
+	/// ## Panics
+	/// If len exceeds `self.remaining().len()`.
+	#[track_caller]
+	fn read_reversed(&mut self, len: usize) -> Vec<u8> {
+		self.try_read_reversed(len).expect("failed to read")
+	}
+
+	/// Try to copy `len` bytes into `out`, reusing its capacity.
+	///
+	/// `out` always gets cleared first.
+	///
+	/// ## Fails
+	/// If `len` exceeds `self.remaining().len()`, in which case nothing
+	/// is read and `out` is left empty.
+	fn try_read_into_vec(
+		&mut self,
+		len: usize,
+		out: &mut Vec<u8>
+	) -> Result<(), ReadError> {
+		out.clear();
+		let slice = self.try_read(len)?;
+		out.extend_from_slice(slice);
+		Ok(())
+	}
+
+	/// Copies `len` bytes into `out`, reusing its capacity.
+	///
+	/// ## Panics
+	/// If `len` exceeds `self.remaining().len()`.
+	#[track_caller]
+	fn read_into_vec(&mut self, len: usize, out: &mut Vec<u8>) {
+		self.try_read_into_vec(len, out).expect("failed to read")
+	}
+
+	/// Try to read all remaining bytes.
+	///
+	/// ## Fails
+	/// If the reader refuses to read all remaining bytes at once, for
+	/// example a `LimitedReader` with a smaller `max_len`, in which
+	/// case nothing is read.
+	fn try_read_remaining(&mut self) -> Result<&[u8], ReadError> {
+		let len = self.remaining().len();
+		// try_read fails if the position is past the end
+		if len == 0 {
+			return Ok(&[])
+		}
+
+		self.try_read(len)
+	}
+
+	/// Reads all remaining bytes.
+	///
+	/// ## Panics
+	/// If the reader refuses to read all remaining bytes at once.
+	#[track_caller]
+	fn read_remaining(&mut self) -> &[u8] {
+		self.try_read_remaining().expect("failed to read remaining")
+	}
+
+	/// Try to read `len` bytes if it is `Some`, otherwise all remaining
+	/// bytes.
+	///
+	/// ## Fails
+	/// If len exceeds `self.remaining().len()` or the reader refuses the
+	/// read, in which case nothing is read.
+	fn try_read_len_or_rest(
+		&mut self,
+		len: Option<usize>
+	) -> Result<&[u8], ReadError> {
+		match len {
+			Some(len) => self.try_read(len),
+			None => self.try_read_remaining()
+		}
+	}
+
+	/// Reads `len` bytes if it is `Some`, otherwise all remaining bytes.
+	///
+	/// ## Panics
+	/// If len exceeds `self.remaining().len()`.
+	#[track_caller]
+	fn read_len_or_rest(&mut self, len: Option<usize>) -> &[u8] {
+		self.try_read_len_or_rest(len).expect("failed to read")
+	}
+
+	/// Copies the remaining bytes into a new `BytesOwned` with the
+	/// position `0`.
+	fn remaining_to_owned(&self) -> BytesOwned {
+		self.remaining().to_vec().into()
+	}
+
+	/// Try to read a given length of bytes, returning the slice together
+	/// with the number of bytes remaining after it.
+	///
+	/// ## Fails
+	/// If len exceeds `self.remaining().len()`, in which case nothing is
+	/// read.
+	fn try_read_with_remaining(
+		&mut self,
+		len: usize
+	) -> Result<(&[u8], usize), ReadError> {
+		let rem = self.remaining().len();
+		self.try_read(len).map(|slice| (slice, rem - len))
+	}
+
+	/// Reads a given length of bytes, returning the slice together
+	/// with the number of bytes remaining after it.
+	///
+	/// ## Panics
+	/// If len exceeds `self.remaining().len()`.
+	#[track_caller]
+	fn read_with_remaining(&mut self, len: usize) -> (&[u8], usize) {
+		self.try_read_with_remaining(len).expect("failed to read")
+	}
+
+	/// Returns `Ok` if at least `len` bytes are remaining.
+	///
+	/// After a successful call the panicking `read_*` methods can be used
+	/// without a panic as long as no more than `len` bytes get read.
+	///
+	/// ## Fails
+	/// If `len` exceeds `self.remaining().len()`.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Bytes, BytesRead, ReadError};
+	/// # fn main() -> Result<(), ReadError> {
+	/// let mut bytes = Bytes::from(&[0u8, 1, 0, 2][..]);
+	/// bytes.require(4)?;
+	/// assert_eq!((bytes.read_u16(), bytes.read_u16()), (1, 2));
+	/// assert!(bytes.require(1).is_err());
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[inline]
+	fn require(&self, len: usize) -> Result<(), ReadError> {
+		if self.remaining().len() < len {
+			Err(ReadError)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Checks once that at least `min_len` bytes remain and then calls `f`.
+	///
+	/// Inside `f` the panicking `read_*` methods can be used without
+	/// a panic as long as no more than `min_len` bytes get read.
+	///
+	/// ## Fails
+	/// If `min_len` exceeds `self.remaining().len()`, in which case `f`
+	/// is not called.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Bytes, BytesRead};
+	/// let mut bytes = Bytes::from(&[0u8, 1, 0, 2][..]);
+	/// let (a, b) = bytes.guarded(4, |b| (b.read_u16(), b.read_u16())).unwrap();
+	/// assert_eq!((a, b), (1, 2));
+	/// ```
+	fn guarded<F, R>(&mut self, min_len: usize, f: F) -> Result<R, ReadError>
+	where
+		F: FnOnce(&mut Self) -> R,
+		Self: Sized
+	{
+		self.require(min_len)?;
+		Ok(f(self))
+	}
+
+	/// Calls `f` `count` times collecting the results into a `Vec`.
+	///
+	/// ## Fails
+	/// If any call to `f` fails, in which case the position gets restored.
+	fn try_read_vec<T, F>(
+		&mut self,
+		count: usize,
+		mut f: F
+	) -> Result<Vec<T>, ReadError>
+	where
+		F: FnMut(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		let start = self.position();
+		// don't trust count to preallocate
+		let mut vec = Vec::with_capacity(count.min(self.remaining().len()));
+		for _ in 0..count {
+			match f(self) {
+				Ok(item) => vec.push(item),
+				Err(e) => {
+					self.seek(start);
+					return Err(e)
+				}
+			}
+		}
+
+		Ok(vec)
+	}
+
+	/// Calls `f` until it returns `None` collecting the results into
+	/// a `Vec`.
+	///
+	/// `f` should consume the sentinel before returning `None`.
+	///
+	/// ## Fails
+	/// If any call to `f` fails or returns an item without consuming
+	/// anything, in which case the position gets restored.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Bytes, BytesRead};
+	/// let mut bytes = Bytes::from(&[1u8, 2, 0, 3][..]);
+	/// let items = bytes.read_until_sentinel(|b| {
+	///     b.try_read_u8().map(|n| Some(n).filter(|n| *n != 0))
+	/// });
+	/// assert_eq!(items, [1, 2]);
+	/// assert_eq!(bytes.remaining(), &[3]);
+	/// ```
+	fn try_read_until_sentinel<T, F>(
+		&mut self,
+		mut f: F
+	) -> Result<Vec<T>, ReadError>
+	where
+		F: FnMut(&mut Self) -> Result<Option<T>, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		let start = self.position();
+		let mut vec = vec![];
+		loop {
+			let pos = self.position();
+			match f(self) {
+				Ok(None) => return Ok(vec),
+				Ok(Some(item)) if self.position() > pos => vec.push(item),
+				_ => {
+					self.seek(start);
+					return Err(ReadError)
+				}
+			}
+		}
+	}
+
+	/// Calls `f` until it returns `None` collecting the results into
+	/// a `Vec`.
+	///
+	/// ## Panics
+	/// If any call to `f` fails or returns an item without consuming
+	/// anything.
+	#[track_caller]
+	fn read_until_sentinel<T, F>(&mut self, f: F) -> Vec<T>
+	where
+		F: FnMut(&mut Self) -> Result<Option<T>, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		self.try_read_until_sentinel(f).expect("failed to read until sentinel")
+	}
+
+	/// Calls `f` exactly `count` times collecting the results into a `Vec`.
+	///
+	/// Use this if the array is prefixed by the amount of elements, for a
+	/// prefix containing the amount of bytes see `try_read_array_by_byte_len`.
+	///
+	/// ## Fails
+	/// If any call to `f` fails, in which case the position gets restored.
+	#[inline]
+	fn try_read_array_by_count<T, F>(
+		&mut self,
+		count: usize,
+		f: F
+	) -> Result<Vec<T>, ReadError>
+	where
+		F: FnMut(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		self.try_read_vec(count, f)
+	}
+
+	/// Calls `f` exactly `count` times collecting the results into a `Vec`.
+	///
+	/// ## Panics
+	/// If any call to `f` fails.
+	#[track_caller]
+	fn read_array_by_count<T, F>(&mut self, count: usize, f: F) -> Vec<T>
+	where
+		F: FnMut(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		self.try_read_array_by_count(count, f).expect("failed to read array")
+	}
+
+	/// Calls `f` until exactly `byte_len` bytes are consumed collecting
+	/// the results into a `Vec`.
+	///
+	/// Use this if the array is prefixed by the amount of bytes, for a
+	/// prefix containing the amount of elements see
+	/// `try_read_array_by_count`.
+	///
+	/// ## Fails
+	/// If there aren't `byte_len` bytes left, any call to `f` fails, `f`
+	/// doesn't consume anything or the last element would cross
+	/// `byte_len`. In which case the position gets restored.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Bytes, BytesRead, BytesSeek};
+	/// let mut bytes = Bytes::from(&[0u8, 1, 0, 2, 3][..]);
+	/// let arr = bytes.read_array_by_byte_len(4, |b| b.try_read_u16());
+	/// assert_eq!(arr, [1, 2]);
+	/// assert!(bytes.try_read_array_by_byte_len(1, |b| b.try_read_u16()).is_err());
+	/// assert_eq!(bytes.position(), 4);
+	/// ```
+	fn try_read_array_by_byte_len<T, F>(
+		&mut self,
+		byte_len: usize,
+		mut f: F
+	) -> Result<Vec<T>, ReadError>
+	where
+		F: FnMut(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		if self.remaining().len() < byte_len {
+			return Err(ReadError)
+		}
+
+		let start = self.position();
+		let mut vec = vec![];
+		let mut consumed = 0;
+		while consumed < byte_len {
+			let item = f(self);
+			let new_consumed = self.position().saturating_sub(start);
+
+			match item {
+				Ok(item) if new_consumed > consumed
+					&& new_consumed <= byte_len =>
+				{
+					vec.push(item);
+					consumed = new_consumed;
+				},
+				_ => {
+					self.seek(start);
+					return Err(ReadError)
+				}
+			}
+		}
+
+		Ok(vec)
+	}
+
+	/// Calls `f` until exactly `byte_len` bytes are consumed collecting
+	/// the results into a `Vec`.
+	///
+	/// ## Panics
+	/// If there aren't `byte_len` bytes left, any call to `f` fails, `f`
+	/// doesn't consume anything or the last element would cross
+	/// `byte_len`.
+	#[track_caller]
+	fn read_array_by_byte_len<T, F>(&mut self, byte_len: usize, f: F) -> Vec<T>
+	where
+		F: FnMut(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		self.try_read_array_by_byte_len(byte_len, f)
+			.expect("failed to read array")
+	}
+
+	/// Calls `f` and returns the bytes it consumed alongside its result.
+	///
+	/// Useful if the original bytes are needed later, for example to
+	/// verify a signature.
+	///
+	/// ## Fails
+	/// If `f` fails or seeks before the start or past the end, in which
+	/// case the position gets restored.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Bytes, BytesRead};
+	/// let mut bytes = Bytes::from(&[0u8, 1, 2][..]);
+	/// let (raw, num) = bytes.try_read_raw_and(|b| b.try_read_u16()).unwrap();
+	/// assert_eq!(raw, &[0, 1]);
+	/// assert_eq!(num, 1);
+	/// ```
+	fn try_read_raw_and<T, F>(&mut self, f: F) -> Result<(&[u8], T), ReadError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		let start = self.position();
+		let res = f(self);
+		let end = self.position();
+
+		match res {
+			Ok(item) if end >= start && end <= Self::as_slice(self).len() => {
+				Ok((&Self::as_slice(self)[start..end], item))
+			},
+			_ => {
+				self.seek(start);
+				Err(ReadError)
+			}
+		}
+	}
+
+	/// Calls `f` and returns the bytes it consumed alongside its result.
+	///
+	/// ## Panics
+	/// If `f` fails or seeks before the start or past the end.
+	#[track_caller]
+	fn read_raw_and<T, F>(&mut self, f: F) -> (&[u8], T)
+	where
+		F: FnOnce(&mut Self) -> Result<T, ReadError>,
+		Self: BytesSeek + Sized
+	{
+		self.try_read_raw_and(f).expect("failed to read")
+	}
+
+	/// Try to read a type implementing `FromBytes`.
+	///
+	/// ## Fails
+	/// If `T::from_bytes` fails.
+	#[inline]
+	fn try_read_typed<T: FromBytes>(&mut self) -> Result<T, ReadError>
+	where Self: Sized {
+		T::from_bytes(self)
+	}
+
+	/// Reads a type implementing `FromBytes`.
+	///
+	/// ## Panics
+	/// If `T::from_bytes` fails.
+	#[inline]
+	#[track_caller]
+	fn read_typed<T: FromBytes>(&mut self) -> T
+	where Self: Sized {
+		self.try_read_typed().expect("failed to read")
+	}
+
+	/// Reads a presence flag (`0` or `1`) and if it is set calls `f`
+	/// to read the value.
+	///
+	/// The flag is always consumed.
+	///
+	/// ## Fails
+	/// If there is no flag, the flag is neither `0` nor `1` or if `f`
+	/// fails.
+	fn try_read_option<T, F>(&mut self, f: F) -> Result<Option<T>, ReadError>
+	where
+		F: FnOnce(&mut Self) -> Result<T, ReadError>,
+		Self: Sized
+	{
+		match self.try_read_u8()? {
+			0 => Ok(None),
+			1 => f(self).map(Some),
+			_ => Err(ReadError)
+		}
+	}
+
+	/// Returns a reader which splits the remaining bytes at each `delim`,
+	/// consuming them while iterating.
+	fn split_iter(&mut self, delim: u8) -> SplitIter<'_, Self>
+	where Self: Sized {
+		SplitIter::new(self, delim)
+	}
+
 	read_fn!(read_u8, try_read_u8, u8, 1);
 	read_fn!(read_u16, try_read_u16, u16, 2);
 	read_fn!(read_u32, try_read_u32, u32, 4);
@@ -149,10 +941,638 @@ pub trait BytesRead {
 	read_le_fn!(read_le_f32, try_read_le_f32, f32, 4);
 	read_le_fn!(read_le_f64, try_read_le_f64, f64, 8);
 
+	read_array_fn!(read_u16_array, try_read_u16_array, u16, 2, from_be_bytes, "big-endian");
+	read_array_fn!(read_u32_array, try_read_u32_array, u32, 4, from_be_bytes, "big-endian");
+	read_array_fn!(read_u64_array, try_read_u64_array, u64, 8, from_be_bytes, "big-endian");
+
+	read_array_fn!(read_i16_array, try_read_i16_array, i16, 2, from_be_bytes, "big-endian");
+	read_array_fn!(read_i32_array, try_read_i32_array, i32, 4, from_be_bytes, "big-endian");
+	read_array_fn!(read_i64_array, try_read_i64_array, i64, 8, from_be_bytes, "big-endian");
+
+	read_array_fn!(read_f32_array, try_read_f32_array, f32, 4, from_be_bytes, "big-endian");
+	read_array_fn!(read_f64_array, try_read_f64_array, f64, 8, from_be_bytes, "big-endian");
+
+	read_array_fn!(read_le_u16_array, try_read_le_u16_array, u16, 2, from_le_bytes, "little-endian");
+	read_array_fn!(read_le_u32_array, try_read_le_u32_array, u32, 4, from_le_bytes, "little-endian");
+	read_array_fn!(read_le_u64_array, try_read_le_u64_array, u64, 8, from_le_bytes, "little-endian");
+
+	read_array_fn!(read_le_i16_array, try_read_le_i16_array, i16, 2, from_le_bytes, "little-endian");
+	read_array_fn!(read_le_i32_array, try_read_le_i32_array, i32, 4, from_le_bytes, "little-endian");
+	read_array_fn!(read_le_i64_array, try_read_le_i64_array, i64, 8, from_le_bytes, "little-endian");
+
+	read_array_fn!(read_le_f32_array, try_read_le_f32_array, f32, 4, from_le_bytes, "little-endian");
+	read_array_fn!(read_le_f64_array, try_read_le_f64_array, f64, 8, from_le_bytes, "little-endian");
+
+	read_into_fn!(read_f32_into, try_read_f32_into, f32, 4, from_be_bytes, "big-endian");
+	read_into_fn!(read_f64_into, try_read_f64_into, f64, 8, from_be_bytes, "big-endian");
+
+	read_into_fn!(read_le_f32_into, try_read_le_f32_into, f32, 4, from_le_bytes, "little-endian");
+	read_into_fn!(read_le_f64_into, try_read_le_f64_into, f64, 8, from_le_bytes, "little-endian");
+
+	read_enum_fn!(read_enum_u8, try_read_enum_u8, u8, 1);
+	read_enum_fn!(read_enum_u16, try_read_enum_u16, u16, 2);
+	read_enum_fn!(read_enum_u32, try_read_enum_u32, u32, 4);
+
+	read_str_fn!(read_str_u8, try_read_str_u8, u8, 1);
+	read_str_fn!(read_str_u16, try_read_str_u16, u16, 2);
+	read_str_fn!(read_str_u32, try_read_str_u32, u32, 4);
+
+	/// Try to read all remaining bytes as a UTF-8 string.
+	///
+	/// ## Fails
+	/// If the remaining bytes are not valid UTF-8 or the reader refuses
+	/// to read them, in which case nothing is read.
+	fn try_read_remaining_str(&mut self) -> Result<&str, StrReadError> {
+		let len = self.remaining().len();
+		std::str::from_utf8(self.remaining()).map_err(StrReadError::from)?;
+
+		let s = self.try_read(len)
+			.map_err(|_| StrReadError::Refused)?;
+		// try_read is not guaranteed to return the bytes which were
+		// just validated, so they need to be checked again
+		std::str::from_utf8(s).map_err(StrReadError::from)
+	}
+
+	/// Reads all remaining bytes as a UTF-8 string.
+	///
+	/// ## Panics
+	/// If the remaining bytes are not valid UTF-8.
+	#[track_caller]
+	fn read_remaining_str(&mut self) -> &str {
+		self.try_read_remaining_str().expect("failed to read str")
+	}
+
+	/// Try to read a `u8` length, if it is equal to `sentinel` the real
+	/// length follows with the width of `ext`.
+	///
+	/// If this fails nothing is read.
+	fn try_read_extended_len_u8(
+		&mut self,
+		sentinel: u8,
+		ext: ExtendedLen
+	) -> Result<usize, ReadError> {
+		let rem = self.remaining();
+		let first = *rem.first().ok_or(ReadError)?;
+		if first != sentinel {
+			self.try_read(1)?;
+			return Ok(first as usize)
+		}
+
+		let (len, read) = match ext {
+			ExtendedLen::U16 => {
+				let bytes = rem.get(1..3).ok_or(ReadError)?;
+				(u16::from_be_bytes([bytes[0], bytes[1]]) as usize, 3)
+			},
+			ExtendedLen::U32 => {
+				let bytes = rem.get(1..5).ok_or(ReadError)?;
+				let len = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+				(len.try_into().map_err(|_| ReadError)?, 5)
+			}
+		};
+		self.try_read(read)?;
+
+		Ok(len)
+	}
+
+	/// Reads a `u8` length, if it is equal to `sentinel` the real
+	/// length follows with the width of `ext`.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left.
+	#[track_caller]
+	fn read_extended_len_u8(&mut self, sentinel: u8, ext: ExtendedLen) -> usize {
+		self.try_read_extended_len_u8(sentinel, ext)
+			.expect("failed to read extended len")
+	}
+
+	/// Try to read a LEB128 encoded `u64`, using at most 10 bytes.
+	///
+	/// If this fails nothing is read.
+	fn try_read_var_u64(&mut self) -> Result<u64, ReadError> {
+		let (num, len) = decode_var_u64(self.remaining()).ok_or(ReadError)?;
+		self.try_read(len)?;
+
+		Ok(num)
+	}
+
+	/// Reads a LEB128 encoded `u64`, using at most 10 bytes.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left or the encoding is invalid.
+	#[track_caller]
+	fn read_var_u64(&mut self) -> u64 {
+		self.try_read_var_u64().expect("failed to read var u64")
+	}
+
+	/// Try to read a LEB128 encoded length followed by that many bytes.
+	///
+	/// If this fails nothing is read.
+	fn try_read_var_prefixed(&mut self) -> Result<&[u8], ReadError> {
+		let (len, len_len) = decode_var_u64(self.remaining())
+			.ok_or(ReadError)?;
+		let len: usize = len.try_into().map_err(|_| ReadError)?;
+		if self.remaining().len() - len_len < len {
+			return Err(ReadError)
+		}
+
+		self.try_read(len_len)?;
+		self.try_read(len)
+	}
+
+	/// Reads a LEB128 encoded length followed by that many bytes.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left or the encoding is invalid.
+	#[track_caller]
+	fn read_var_prefixed(&mut self) -> &[u8] {
+		self.try_read_var_prefixed().expect("failed to read var prefixed")
+	}
+
+	/// Try to read a map of key-value pairs.
+	///
+	/// Expects a `u32` count followed by that many pairs, where the key and
+	/// the value are each prefixed by their length as a `u32`. All numbers
+	/// are in big-endian.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left for all pairs or the reader
+	/// refuses to read them at once, in which case nothing is read.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Bytes, BytesRead};
+	/// let mut bytes = Bytes::from(&[
+	///     0, 0, 0, 1,
+	///     0, 0, 0, 1, b'k',
+	///     0, 0, 0, 2, b'v', b'v'
+	/// ][..]);
+	/// let map = bytes.try_read_kv_map_u32().unwrap();
+	/// assert_eq!(map, [(&b"k"[..], &b"vv"[..])]);
+	/// ```
+	#[allow(clippy::type_complexity)]
+	fn try_read_kv_map_u32(
+		&mut self
+	) -> Result<Vec<(&[u8], &[u8])>, ReadError> {
+		// find the length first so nothing is read on failure
+		let len = split_kv_map_u32(self.remaining(), |_, _| {})
+			.ok_or(ReadError)?;
+		let slice = self.try_read(len)?;
+
+		let mut map = vec![];
+		split_kv_map_u32(slice, |key, value| map.push((key, value)))
+			.ok_or(ReadError)?;
+		Ok(map)
+	}
+
+	/// Try to read the raw bits of an `f32` in big-endian.
+	#[inline]
+	fn try_read_f32_bits(&mut self) -> Result<u32, ReadError> {
+		self.try_read_u32()
+	}
+
+	/// Reads the raw bits of an `f32` in big-endian.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left.
+	#[inline]
+	#[track_caller]
+	fn read_f32_bits(&mut self) -> u32 {
+		self.try_read_f32_bits().expect("failed to read f32 bits")
+	}
+
+	/// Try to read the raw bits of an `f64` in big-endian.
+	#[inline]
+	fn try_read_f64_bits(&mut self) -> Result<u64, ReadError> {
+		self.try_read_u64()
+	}
+
+	/// Reads the raw bits of an `f64` in big-endian.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left.
+	#[inline]
+	#[track_caller]
+	fn read_f64_bits(&mut self) -> u64 {
+		self.try_read_f64_bits().expect("failed to read f64 bits")
+	}
+
+	/// Try to read an `f32` in big-endian, rejecting signaling NaNs.
+	///
+	/// If this fails nothing is read.
+	fn try_read_f32_nan_checked(&mut self) -> Result<f32, ReadError> {
+		let bits = self.peek(4)
+			.and_then(|b| b.try_into().ok())
+			.map(u32::from_be_bytes)
+			.ok_or(ReadError)?;
+		let is_nan = bits & 0x7f80_0000 == 0x7f80_0000 && bits & 0x007f_ffff != 0;
+		if is_nan && bits & 0x0040_0000 == 0 {
+			return Err(ReadError)
+		}
+
+		self.try_read_f32()
+	}
+
+	/// Try to read an `f64` in big-endian, rejecting signaling NaNs.
+	///
+	/// If this fails nothing is read.
+	fn try_read_f64_nan_checked(&mut self) -> Result<f64, ReadError> {
+		let bits = self.peek(8)
+			.and_then(|b| b.try_into().ok())
+			.map(u64::from_be_bytes)
+			.ok_or(ReadError)?;
+		let exp = 0x7ff0_0000_0000_0000;
+		let is_nan = bits & exp == exp && bits & 0x000f_ffff_ffff_ffff != 0;
+		if is_nan && bits & 0x0008_0000_0000_0000 == 0 {
+			return Err(ReadError)
+		}
+
+		self.try_read_f64()
+	}
+
+	/// Try to read 8 bytes in big-endian converting them into an `i64`
+	/// which gets divided by `10^scale`.
+	///
+	/// ## Precision
+	/// An `f64` cannot represent every scaled value exactly.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left or `scale` exceeds `i32::MAX`,
+	/// in which case nothing is read.
+	#[inline]
+	fn try_read_fixed_i64(&mut self, scale: u32) -> Result<f64, ReadError> {
+		let scale: i32 = scale.try_into().map_err(|_| ReadError)?;
+		self.try_read_i64()
+			.map(|num| num as f64 / 10f64.powi(scale))
+	}
+
+	/// Reads 8 bytes in big-endian converting them into an `i64`
+	/// which gets divided by `10^scale`.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left or `scale` exceeds `i32::MAX`.
+	#[inline]
+	#[track_caller]
+	fn read_fixed_i64(&mut self, scale: u32) -> f64 {
+		self.try_read_fixed_i64(scale).expect("failed to read fixed i64")
+	}
+
+	/// Try to read a Windows `FILETIME`, the amount of 100 nanosecond
+	/// intervals since 1601-01-01 as a `u64` in little-endian.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left or the time can't be represented
+	/// as a `SystemTime`, in which case nothing is read.
+	fn try_read_filetime(&mut self) -> Result<SystemTime, ReadError> {
+		let time = self.peek(8)
+			.and_then(|b| b.try_into().ok())
+			.map(u64::from_le_bytes)
+			.and_then(filetime_to_system_time)
+			.ok_or(ReadError)?;
+
+		self.try_read(8)?;
+		Ok(time)
+	}
+
+	/// Reads a Windows `FILETIME`, see `try_read_filetime`.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left or the time can't be represented
+	/// as a `SystemTime`.
+	#[track_caller]
+	fn read_filetime(&mut self) -> SystemTime {
+		self.try_read_filetime().expect("failed to read filetime")
+	}
+
+	/// Try to read the amount of milliseconds since 1970-01-01 as an `i64`
+	/// in big-endian, negative values are before 1970.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left or the time can't be represented
+	/// as a `SystemTime`, in which case nothing is read.
+	fn try_read_unix_millis(&mut self) -> Result<SystemTime, ReadError> {
+		let time = self.peek(8)
+			.and_then(|b| b.try_into().ok())
+			.map(i64::from_be_bytes)
+			.and_then(unix_millis_to_system_time)
+			.ok_or(ReadError)?;
+
+		self.try_read(8)?;
+		Ok(time)
+	}
+
+	/// Reads the amount of milliseconds since 1970-01-01, see
+	/// `try_read_unix_millis`.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left or the time can't be represented
+	/// as a `SystemTime`.
+	#[track_caller]
+	fn read_unix_millis(&mut self) -> SystemTime {
+		self.try_read_unix_millis().expect("failed to read unix millis")
+	}
+
 	/// Tries to read a given length without updating
 	/// the internal position. Returns `None` if there are not enought
 	/// bytes remaining.
 	fn peek(&self, len: usize) -> Option<&[u8]>;
+
+	/// Returns `len` bytes starting at `start` without updating the
+	/// internal position. Returns `None` if the range exceeds the slice.
+	///
+	/// `start` is relative to the beginning of the entire slice.
+	#[inline]
+	fn slice_from(&self, start: usize, len: usize) -> Option<&[u8]> {
+		self.as_slice().get(start..)?.get(..len)
+	}
+
+	/// Returns all bytes in `range` folded with XOR.
+	///
+	/// `range` is relative to the beginning of the entire slice.
+	///
+	/// ## Fails
+	/// If the range exceeds the slice.
+	fn try_xor_checksum(&self, range: Range<usize>) -> Result<u8, ReadError> {
+		self.as_slice().get(range)
+			.map(xor_checksum)
+			.ok_or(ReadError)
+	}
+
+	/// Returns all bytes in `range` folded with XOR.
+	///
+	/// ## Panics
+	/// If the range exceeds the slice.
+	#[track_caller]
+	fn xor_checksum(&self, range: Range<usize>) -> u8 {
+		self.try_xor_checksum(range).expect("range out of bounds")
+	}
+
+	/// Returns the slices for each `(offset, len)` pair without updating
+	/// the internal position.
+	///
+	/// ## Fails
+	/// If any range exceeds the slice.
+	fn read_at_offsets(
+		&self,
+		specs: &[(usize, usize)]
+	) -> Result<Vec<&[u8]>, ReadError> {
+		specs.iter()
+			.map(|(start, len)| self.slice_from(*start, *len).ok_or(ReadError))
+			.collect()
+	}
+
+	/// Returns up to `len` bytes without updating the internal position.
+	#[inline]
+	fn peek_up_to(&self, len: usize) -> &[u8] {
+		let rem = self.remaining();
+		&rem[..len.min(rem.len())]
+	}
+
+	/// Returns the next byte without updating the internal position.
+	/// Returns `None` if there are no bytes remaining.
+	#[inline]
+	fn peek_u8(&self) -> Option<u8> {
+		self.remaining().first().copied()
+	}
+
+	/// Reads the next byte. Returns `None` if there are no bytes remaining.
+	#[inline]
+	fn next_u8(&mut self) -> Option<u8> {
+		self.try_read_u8().ok()
+	}
+
+	/// Returns how many consecutive `byte`s there are starting at
+	/// the current position, without updating the position.
+	fn count_leading(&self, byte: u8) -> usize {
+		self.remaining().iter()
+			.take_while(|b| **b == byte)
+			.count()
+	}
+
+	/// Skips all consecutive `byte`s starting at the current position,
+	/// returning how many were skipped.
+	///
+	/// If the reader refuses to skip all of them at once, for example a
+	/// `LimitedReader`, as many as the reader allows get skipped.
+	fn skip_leading(&mut self, byte: u8) -> usize {
+		let count = self.count_leading(byte);
+		if count == 0 || self.try_skip_within(count).is_ok() {
+			return count
+		}
+
+		// skip one by one until the reader refuses
+		let mut skipped = 0;
+		while skipped < count && self.try_skip_within(1).is_ok() {
+			skipped += 1;
+		}
+
+		skipped
+	}
+
+	/// Try to read a GUID stored in the Microsoft mixed-endian layout,
+	/// returning it in the canonical byte order.
+	///
+	/// The first three fields are stored little-endian, the rest as is.
+	///
+	/// If this fails nothing is read.
+	fn try_read_guid_le(&mut self) -> Result<[u8; 16], ReadError> {
+		let guid: [u8; 16] = self.try_read(16)?
+			.try_into()
+			.map_err(|_| ReadError)?;
+
+		Ok(swap_guid_fields(guid))
+	}
+
+	/// Reads a GUID stored in the Microsoft mixed-endian layout,
+	/// returning it in the canonical byte order.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left.
+	#[track_caller]
+	fn read_guid_le(&mut self) -> [u8; 16] {
+		self.try_read_guid_le().expect("failed to read guid")
+	}
+
+	/// Try to read the next byte as two nibbles, returning the high
+	/// and then the low nibble.
+	///
+	/// If this fails nothing is read.
+	#[inline]
+	fn try_read_nibbles(&mut self) -> Result<(u8, u8), ReadError> {
+		self.try_read_u8().map(|b| (b >> 4, b & 0x0f))
+	}
+
+	/// Reads the next byte as two nibbles, returning the high and then
+	/// the low nibble.
+	///
+	/// ## Panics
+	/// If there are no bytes remaining.
+	#[inline]
+	#[track_caller]
+	fn read_nibbles(&mut self) -> (u8, u8) {
+		self.try_read_nibbles().expect("failed to read nibbles")
+	}
+
+	/// Try to read a BCD encoded byte where the high nibble is the tens
+	/// and the low nibble the ones digit.
+	///
+	/// ## Fails
+	/// If there are no bytes remaining or a nibble is bigger than 9,
+	/// in which case nothing is read.
+	fn try_read_bcd_u8(&mut self) -> Result<u8, ReadError> {
+		let b = self.peek_u8().ok_or(ReadError)?;
+		let (hi, lo) = (b >> 4, b & 0x0f);
+		if hi > 9 || lo > 9 {
+			return Err(ReadError)
+		}
+
+		self.try_read_u8()?;
+		Ok(hi * 10 + lo)
+	}
+
+	/// Reads a BCD encoded byte.
+	///
+	/// ## Panics
+	/// If there are no bytes remaining or a nibble is bigger than 9.
+	#[track_caller]
+	fn read_bcd_u8(&mut self) -> u8 {
+		self.try_read_bcd_u8().expect("failed to read bcd")
+	}
+
+	/// Returns the next `u16` in the given byte order without updating
+	/// the internal position. Returns `None` if there are not enough
+	/// bytes remaining.
+	fn peek_u16_endian(&self, endian: Endian) -> Option<u16> {
+		let bytes = self.peek(2)?.try_into().ok()?;
+		Some(match endian {
+			Endian::Big => u16::from_be_bytes(bytes),
+			Endian::Little => u16::from_le_bytes(bytes)
+		})
+	}
+
+	/// Returns the next `u32` in the given byte order without updating
+	/// the internal position. Returns `None` if there are not enough
+	/// bytes remaining.
+	fn peek_u32_endian(&self, endian: Endian) -> Option<u32> {
+		let bytes = self.peek(4)?.try_into().ok()?;
+		Some(match endian {
+			Endian::Big => u32::from_be_bytes(bytes),
+			Endian::Little => u32::from_le_bytes(bytes)
+		})
+	}
+
+	/// Try to read `count` `u32`s in the given byte order.
+	///
+	/// If this fails nothing is read.
+	fn try_read_u32_vec_endian(
+		&mut self,
+		count: usize,
+		endian: Endian
+	) -> Result<Vec<u32>, ReadError> {
+		let len = count.checked_mul(4).ok_or(ReadError)?;
+		let from_bytes = match endian {
+			Endian::Big => u32::from_be_bytes,
+			Endian::Little => u32::from_le_bytes
+		};
+
+		Ok(self.try_read(len)?
+			.chunks_exact(4)
+			.map(|c| from_bytes(c.try_into().unwrap()))
+			.collect())
+	}
+
+	/// Reads `count` `u32`s in the given byte order.
+	///
+	/// ## Panics
+	/// If there aren't enough bytes left.
+	#[track_caller]
+	fn read_u32_vec_endian(&mut self, count: usize, endian: Endian) -> Vec<u32> {
+		self.try_read_u32_vec_endian(count, endian).expect("failed to read")
+	}
+
+	/// Returns an iterator over the remaining bytes together with their
+	/// offset from the start of the slice.
+	fn enumerate_remaining(
+		&self
+	) -> Zip<RangeFrom<usize>, Copied<slice::Iter<'_, u8>>> {
+		let rem = self.remaining();
+		let start = self.len() - rem.len();
+		(start..).zip(rem.iter().copied())
+	}
+
+	/// Returns an iterator over the entire slice together with each
+	/// offset.
+	fn enumerate_all(
+		&self
+	) -> Zip<RangeFrom<usize>, Copied<slice::Iter<'_, u8>>> {
+		(0..).zip(self.as_slice().iter().copied())
+	}
+
+	/// Returns `true` if the remaining bytes start with `prefix`.
+	#[inline]
+	fn starts_with(&self, prefix: &[u8]) -> bool {
+		self.remaining().starts_with(prefix)
+	}
+
+	/// Returns `true` if the entire slice ends with `suffix`.
+	#[inline]
+	fn buffer_ends_with(&self, suffix: &[u8]) -> bool {
+		self.as_slice().ends_with(suffix)
+	}
+
+	/// Reads `magic.len()` bytes and checks that they are equal to `magic`.
+	///
+	/// ## Fails
+	/// If the bytes are not equal, in which case nothing is read.
+	fn expect_magic(&mut self, magic: &[u8]) -> Result<(), ReadError> {
+		if !self.starts_with(magic) {
+			return Err(ReadError)
+		}
+
+		self.try_read(magic.len()).map(|_| ())
+	}
+
+	/// Checks that all bytes were read.
+	///
+	/// ## Fails
+	/// If there are bytes remaining, use `remaining_len` to know how many.
+	#[inline]
+	fn expect_consumed(&self) -> Result<(), ReadError> {
+		if self.at_end() {
+			Ok(())
+		} else {
+			Err(ReadError)
+		}
+	}
+
+	/// Reads `expected.len()` bytes and checks that they are equal to
+	/// `expected`.
+	///
+	/// Unlike `expect_magic` the bytes are read even if they don't match,
+	/// so the parser can continue after a reserved field.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left, in which case nothing is read,
+	/// or if the bytes are not equal.
+	fn read_expect(&mut self, expected: &[u8]) -> Result<(), ReadError> {
+		if self.try_read(expected.len())? != expected {
+			return Err(ReadError)
+		}
+
+		Ok(())
+	}
+
+	/// Reads `len` bytes and checks that they are all zero.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left, in which case nothing is read,
+	/// or if any byte is not zero.
+	fn read_expect_zero(&mut self, len: usize) -> Result<(), ReadError> {
+		if self.try_read(len)?.iter().any(|b| *b != 0) {
+			return Err(ReadError)
+		}
+
+		Ok(())
+	}
 }
 
 impl<R: BytesRead> BytesRead for &mut R {
@@ -211,6 +1631,52 @@ pub trait BytesReadRef<'a>: BytesRead {
 	/// the internal position. Returns `None` if there are not enought
 	/// bytes remaining.
 	fn peek_ref(&self, len: usize) -> Option<&'a [u8]>;
+
+	/// Try to read all remaining bytes as a UTF-8 string.
+	///
+	/// ## Fails
+	/// If the remaining bytes are not valid UTF-8 or the reader refuses
+	/// to read them, in which case nothing is read.
+	fn try_read_remaining_str_ref(&mut self) -> Result<&'a str, StrReadError> {
+		let s = std::str::from_utf8(self.remaining_ref())
+			.map_err(StrReadError::from)?;
+		self.try_read_ref(s.len())
+			.map_err(|_| StrReadError::Refused)?;
+
+		Ok(s)
+	}
+
+	/// Reads all remaining bytes as a UTF-8 string.
+	///
+	/// ## Panics
+	/// If the remaining bytes are not valid UTF-8.
+	#[track_caller]
+	fn read_remaining_str_ref(&mut self) -> &'a str {
+		self.try_read_remaining_str_ref().expect("failed to read str")
+	}
+
+	/// Try to read a map of length prefixed keys and values, keeping the
+	/// lifetime `'a`.
+	///
+	/// Expects the same layout as `BytesRead::try_read_kv_map_u32`.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left for all pairs or the reader
+	/// refuses to read them at once, in which case nothing is read.
+	#[allow(clippy::type_complexity)]
+	fn try_read_kv_map_u32_ref(
+		&mut self
+	) -> Result<Vec<(&'a [u8], &'a [u8])>, ReadError> {
+		// find the length first so nothing is read on failure
+		let len = split_kv_map_u32(self.remaining_ref(), |_, _| {})
+			.ok_or(ReadError)?;
+		let slice = self.try_read_ref(len)?;
+
+		let mut map = vec![];
+		split_kv_map_u32(slice, |key, value| map.push((key, value)))
+			.ok_or(ReadError)?;
+		Ok(map)
+	}
 }
 
 impl<'a, R: BytesReadRef<'a>> BytesReadRef<'a> for &mut R {
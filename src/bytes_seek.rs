@@ -1,26 +1,78 @@
 
+use crate::BytesRead;
+use crate::util::seek_from_to_pos;
+
 use std::fmt;
+use std::io::SeekFrom;
 
 /// Get's returned when there is not enough data left to seek to the position.
-///
-/// The max position get's returned.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SeekError(pub usize);
+pub struct SeekError {
+	/// The position that was attempted, `0` if it would be negative.
+	pub attempted: usize,
+	/// The max position.
+	pub max: usize
+}
 
 impl fmt::Display for SeekError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		fmt::Debug::fmt(self, f)
+		write!(
+			f,
+			"tried to seek to {} but the max position is {}",
+			self.attempted, self.max
+		)
 	}
 }
 
 impl std::error::Error for SeekError {}
 
+/// Get's returned by `BytesSeek::try_seek_from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFromError {
+	/// The position would be before the start.
+	BeforeStart,
+	/// The position would not fit into a `usize`.
+	Overflow,
+	/// The position is not valid, see `BytesSeek::try_seek`.
+	Seek(SeekError)
+}
+
+impl From<SeekError> for SeekFromError {
+	fn from(e: SeekError) -> Self {
+		Self::Seek(e)
+	}
+}
+
+impl fmt::Display for SeekFromError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::BeforeStart => f.write_str("tried to seek before the start"),
+			Self::Overflow => f.write_str("seek position overflowed"),
+			Self::Seek(e) => fmt::Display::fmt(e, f)
+		}
+	}
+}
+
+impl std::error::Error for SeekFromError {}
+
+#[track_caller]
+fn assert_power_of_two(align: usize) {
+	assert!(align.is_power_of_two(), "align must be a power of two");
+}
+
 
 /// Sets the internal position for writing or reading.
 pub trait BytesSeek {
 	/// Returns the internal position.
 	fn position(&self) -> usize;
 
+	/// Returns the position in the underlying buffer, ignoring any
+	/// offset applied by wrappers like `Offset`.
+	#[inline]
+	fn absolute_position(&self) -> usize {
+		self.position()
+	}
+
 	/// Sets the internal position if possible.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError>;
 
@@ -43,6 +95,106 @@ pub trait BytesSeek {
 	fn advance(&mut self, adv: usize) {
 		self.try_advance(adv).expect("failed to advance")
 	}
+
+	/// Advances the internal position by at most `adv` without going
+	/// past the end, returning how far it actually advanced.
+	///
+	/// `Vec` backends don't grow, the position stops at their length.
+	/// If the position is already past the end, it stays unchanged.
+	fn advance_saturating(&mut self, adv: usize) -> usize
+	where Self: BytesRead {
+		let pos = self.position();
+		let end = pos.saturating_add(adv).min(self.len());
+		if end <= pos {
+			return 0
+		}
+
+		self.seek(end);
+		end - pos
+	}
+
+	/// Returns `true` if the position is a multiple of `align`.
+	///
+	/// ## Panics
+	/// If `align` is not a power of two.
+	#[track_caller]
+	fn is_aligned_to(&self, align: usize) -> bool {
+		assert_power_of_two(align);
+		self.position() & (align - 1) == 0
+	}
+
+	/// Advances the internal position to the next multiple of `align`
+	/// if possible. Does nothing if the position is already aligned.
+	///
+	/// ## Panics
+	/// If `align` is not a power of two.
+	#[track_caller]
+	fn try_align_to(&mut self, align: usize) -> Result<(), SeekError> {
+		assert_power_of_two(align);
+		let pos = self.position();
+		let rem = pos & (align - 1);
+		if rem == 0 {
+			return Ok(())
+		}
+
+		self.try_seek(pos + (align - rem))
+	}
+
+	/// Advances the internal position to the next multiple of `align`.
+	///
+	/// ## Panics
+	/// If `align` is not a power of two or depending on the
+	/// `BytesSeek::seek` implementation.
+	#[track_caller]
+	fn align_to(&mut self, align: usize) {
+		self.try_align_to(align).expect("failed to align")
+	}
+
+	/// Sets the internal position `back` bytes before the end if possible.
+	///
+	/// ## Fails
+	/// If `back` is bigger than the length or the position is not valid,
+	/// in which case the position is not updated.
+	fn try_seek_from_end(&mut self, back: usize) -> Result<(), SeekFromError>
+	where Self: BytesRead {
+		let pos = self.len().checked_sub(back)
+			.ok_or(SeekFromError::BeforeStart)?;
+		self.try_seek(pos)?;
+		Ok(())
+	}
+
+	/// Sets the internal position `back` bytes before the end.
+	///
+	/// ## Panics
+	/// If `back` is bigger than the length.
+	#[track_caller]
+	fn seek_from_end(&mut self, back: usize)
+	where Self: BytesRead {
+		self.try_seek_from_end(back).expect("failed to seek from end")
+	}
+
+	/// Sets the internal position relative to the start, the end or the
+	/// current position if possible, returning the new position.
+	///
+	/// Like `io::Seek::seek` but returns a `SeekFromError` and works for
+	/// any `BytesSeek` implementation. This crate depends on `std`, so
+	/// `SeekFrom` is the one from `std::io`.
+	///
+	/// ## Fails
+	/// If the position would be before the start, doesn't fit into a
+	/// `usize` or is not valid, in which case the position is not updated.
+	fn try_seek_from(&mut self, from: SeekFrom) -> Result<usize, SeekFromError>
+	where Self: BytesRead {
+		let pos = seek_from_to_pos(self.len(), self.position(), from)?;
+		self.try_seek(pos)?;
+		Ok(pos)
+	}
+
+	/// Returns how many bytes the internal position is before the end.
+	fn position_from_end(&self) -> usize
+	where Self: BytesRead {
+		self.len().saturating_sub(self.position())
+	}
 }
 
 impl<S: BytesSeek> BytesSeek for &mut S {
@@ -51,6 +203,11 @@ impl<S: BytesSeek> BytesSeek for &mut S {
 		(**self).position()
 	}
 
+	#[inline]
+	fn absolute_position(&self) -> usize {
+		(**self).absolute_position()
+	}
+
 	#[inline]
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
 		(**self).try_seek(pos)
@@ -1,7 +1,13 @@
 
-use crate::Bytes;
+use crate::{Bytes, BytesSeek, ToBytes, Endian};
+use crate::util::{
+	encode_var_u64, swap_guid_fields, xor_checksum,
+	system_time_to_filetime, system_time_to_unix_millis
+};
 
 use std::fmt;
+use std::ops::Range;
+use std::time::SystemTime;
 
 macro_rules! write_fn {
 	($name:ident, $try_name:ident, $type:ident) => (
@@ -57,6 +63,78 @@ macro_rules! write_le_fn {
 	}
 }
 
+macro_rules! write_slice_fn {
+	($name:ident, $try_name:ident, $type:ident, $to_bytes:ident,
+	$endian:expr) => (
+		write_slice_fn!(
+			$name, $try_name, $type, $to_bytes, $endian, stringify!($type)
+		);
+	);
+	($name:ident, $try_name:ident, $type:ident, $to_bytes:ident,
+	$endian:expr, $type_str:expr) => {
+		#[doc = "Try to write all `"]
+		#[doc = $type_str]
+		#[doc = "`s in "]
+		#[doc = $endian]
+		#[doc = "."]
+		///
+		/// If this fails nothing is written.
+		fn $try_name(&mut self, nums: &[$type]) -> Result<(), WriteError>
+		where Self: BytesSeek {
+			try_write_encoded(self, nums, $type::$to_bytes)
+		}
+
+		#[track_caller]
+		#[doc = "Writes all `"]
+		#[doc = $type_str]
+		#[doc = "`s in "]
+		#[doc = $endian]
+		#[doc = "."]
+		///
+		/// ## Panics
+		/// If there aren't enough remaining bytes left.
+		fn $name(&mut self, nums: &[$type])
+		where Self: BytesSeek {
+			self.$try_name(nums).expect("failed to write")
+		}
+	}
+}
+
+// writes every num encoded with to_bytes, directly into the remaining
+// bytes if they are long enough, nothing is written if not all fit
+fn try_write_encoded<W, T, const N: usize>(
+	writer: &mut W,
+	nums: &[T],
+	to_bytes: impl Fn(T) -> [u8; N]
+) -> Result<(), WriteError>
+where
+	W: BytesWrite + BytesSeek + ?Sized,
+	T: Copy
+{
+	let len = nums.len().checked_mul(N).ok_or(WriteError)?;
+	let start = writer.position();
+	let end = start.checked_add(len).ok_or(WriteError)?;
+
+	// check the capacity before writing anything
+	writer.try_seek(end).map_err(|_| WriteError)?;
+	writer.seek(start);
+
+	if let Some(buf) = writer.remaining_mut().get_mut(..len) {
+		for (b, num) in buf.chunks_exact_mut(N).zip(nums) {
+			b.copy_from_slice(&to_bytes(*num));
+		}
+		writer.seek(end);
+		return Ok(())
+	}
+
+	// growable backends allocate while writing
+	for num in nums {
+		writer.try_write(to_bytes(*num))?;
+	}
+
+	Ok(())
+}
+
 /// Get's returned when there is not enough space to write everything.
 /// If this get's returned nothing should be written.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -94,6 +172,309 @@ pub trait BytesWrite {
 		self.try_write(slice).expect("failed to write")
 	}
 
+	/// Try to write a slice in reverse order.
+	///
+	/// ## Fails
+	/// If there aren't enough remaining bytes left, in which case nothing
+	/// is written.
+	fn try_write_reversed(&mut self, slice: &[u8]) -> Result<(), WriteError>
+	where Self: BytesSeek {
+		let start = self.position();
+		self.try_write(slice)?;
+		self.as_mut()[start..][..slice.len()].reverse();
+		Ok(())
+	}
+
+	/// Writes a slice in reverse order.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[track_caller]
+	fn write_reversed(&mut self, slice: &[u8])
+	where Self: BytesSeek {
+		self.try_write_reversed(slice).expect("failed to write")
+	}
+
+	/// Returns the bytes before the position as a bytes struct
+	/// setting the position of the new Bytes to `0`.
+	fn written_bytes(&self) -> Bytes<'_>
+	where Self: BytesSeek {
+		let slice = self.as_bytes().inner();
+		slice[..self.position().min(slice.len())].into()
+	}
+
+	/// Writes as much of the slice as fits, returning how many bytes
+	/// were written.
+	///
+	/// Growable buffers always write the entire slice.
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		let len = slice.len().min(self.remaining_mut().len());
+		match self.try_write(&slice[..len]) {
+			Ok(()) => len,
+			Err(_) => 0
+		}
+	}
+
+	/// Writes all slices in order.
+	///
+	/// If this fails nothing is written.
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		let len: usize = slices.iter().map(|s| s.len()).sum();
+		if self.remaining_mut().len() >= len {
+			for slice in slices {
+				self.try_write(slice)?;
+			}
+
+			return Ok(())
+		}
+
+		// the buffer might still be able to grow
+		let mut buf = Vec::with_capacity(len);
+		for slice in slices {
+			buf.extend_from_slice(slice);
+		}
+		self.try_write(buf)
+	}
+
+	/// Writes all slices in order.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[track_caller]
+	fn write_vectored(&mut self, slices: &[&[u8]]) {
+		self.try_write_vectored(slices).expect("failed to write")
+	}
+
+	/// Try to pad the buffer with `fill` until it is `total_len` long,
+	/// leaving the position at `total_len`.
+	///
+	/// Fixed size buffers get filled from the position to `total_len`.
+	/// Growable buffers keep their existing bytes and only get filled
+	/// from the position or their end, whichever is further.
+	///
+	/// ## Fails
+	/// If the position can't be set to `total_len`, in which case nothing
+	/// is written.
+	fn try_pad_to(&mut self, total_len: usize, fill: u8) -> Result<(), WriteError>
+	where Self: BytesSeek {
+		let pos = self.position();
+		let len = self.as_bytes().inner().len();
+
+		// check the capacity before writing anything
+		self.try_seek(total_len).map_err(|_| WriteError)?;
+		// only growable buffers can seek past their end
+		let growable = total_len > len || len.checked_add(1)
+			.map_or(false, |end| self.try_seek(end).is_ok());
+
+		let start = if growable { pos.max(len) } else { pos };
+		if start < total_len {
+			self.seek(start);
+
+			let chunk = [fill; 64];
+			let mut left = total_len - start;
+			while left > 0 {
+				let len = left.min(chunk.len());
+				self.try_write(&chunk[..len])?;
+				left -= len;
+			}
+		}
+
+		self.seek(total_len);
+		Ok(())
+	}
+
+	/// Pads the buffer with `fill` until it is `total_len` long, see
+	/// `try_pad_to`.
+	///
+	/// ## Panics
+	/// If the position can't be set to `total_len`.
+	#[track_caller]
+	fn pad_to(&mut self, total_len: usize, fill: u8)
+	where Self: BytesSeek {
+		self.try_pad_to(total_len, fill).expect("failed to pad")
+	}
+
+	/// Try to write a run, the `count` followed by `count` copies
+	/// of `byte`.
+	///
+	/// If this fails nothing is written.
+	fn try_write_run(&mut self, byte: u8, count: u8) -> Result<(), WriteError> {
+		let mut run = [byte; 256];
+		run[0] = count;
+		self.try_write(&run[..=count as usize])
+	}
+
+	/// Writes a run, the `count` followed by `count` copies of `byte`.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[track_caller]
+	fn write_run(&mut self, byte: u8, count: u8) {
+		self.try_write_run(byte, count).expect("failed to write run")
+	}
+
+	/// Try to write a type implementing `ToBytes`.
+	///
+	/// ## Fails
+	/// If `T::to_bytes` fails.
+	#[inline]
+	fn try_write_typed<T: ToBytes>(&mut self, value: &T) -> Result<(), WriteError>
+	where Self: Sized {
+		value.to_bytes(self)
+	}
+
+	/// Writes a type implementing `ToBytes`.
+	///
+	/// ## Panics
+	/// If `T::to_bytes` fails.
+	#[inline]
+	#[track_caller]
+	fn write_typed<T: ToBytes>(&mut self, value: &T)
+	where Self: Sized {
+		self.try_write_typed(value).expect("failed to write")
+	}
+
+	/// Writes a presence flag (`0` or `1`) and if `value` is `Some`
+	/// calls `f` to write it.
+	///
+	/// ## Fails
+	/// If the flag could not be written or if `f` fails.
+	fn try_write_option<T, F>(
+		&mut self,
+		value: Option<T>,
+		f: F
+	) -> Result<(), WriteError>
+	where
+		F: FnOnce(&mut Self, T) -> Result<(), WriteError>,
+		Self: Sized
+	{
+		match value {
+			Some(v) => {
+				self.try_write_u8(1)?;
+				f(self, v)
+			},
+			None => self.try_write_u8(0)
+		}
+	}
+
+	/// Try to write all `u32`s in the given byte order.
+	///
+	/// If this fails nothing is written.
+	fn try_write_u32_slice_endian(
+		&mut self,
+		nums: &[u32],
+		endian: Endian
+	) -> Result<(), WriteError>
+	where Self: BytesSeek {
+		let to_bytes = match endian {
+			Endian::Big => u32::to_be_bytes,
+			Endian::Little => u32::to_le_bytes
+		};
+
+		try_write_encoded(self, nums, to_bytes)
+	}
+
+	/// Writes all `u32`s in the given byte order.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[track_caller]
+	fn write_u32_slice_endian(&mut self, nums: &[u32], endian: Endian)
+	where Self: BytesSeek {
+		self.try_write_u32_slice_endian(nums, endian).expect("failed to write")
+	}
+
+	/// Try to write a GUID given in the canonical byte order using the
+	/// Microsoft mixed-endian layout.
+	///
+	/// If this fails nothing is written.
+	#[inline]
+	fn try_write_guid_le(&mut self, guid: [u8; 16]) -> Result<(), WriteError> {
+		self.try_write(swap_guid_fields(guid))
+	}
+
+	/// Writes a GUID given in the canonical byte order using the
+	/// Microsoft mixed-endian layout.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[track_caller]
+	fn write_guid_le(&mut self, guid: [u8; 16]) {
+		self.try_write_guid_le(guid).expect("failed to write guid")
+	}
+
+	/// Try to write the XOR checksum of the bytes in `range`.
+	///
+	/// `range` is relative to the beginning of the entire slice.
+	///
+	/// ## Fails
+	/// If the range exceeds the slice or there isn't enough space left,
+	/// in which case nothing is written.
+	fn try_write_xor_checksum_of(
+		&mut self,
+		range: Range<usize>
+	) -> Result<(), WriteError> {
+		let checksum = self.as_bytes().inner().get(range)
+			.map(xor_checksum)
+			.ok_or(WriteError)?;
+
+		self.try_write_u8(checksum)
+	}
+
+	/// Writes the XOR checksum of the bytes in `range`.
+	///
+	/// ## Panics
+	/// If the range exceeds the slice or there isn't enough space left.
+	#[track_caller]
+	fn write_xor_checksum_of(&mut self, range: Range<usize>) {
+		self.try_write_xor_checksum_of(range).expect("failed to write checksum")
+	}
+
+	/// Try to write two nibbles into one byte, `hi` are the upper
+	/// four bits.
+	///
+	/// ## Fails
+	/// If a nibble is bigger than 15 or there isn't enough space left,
+	/// in which case nothing is written.
+	fn try_write_nibbles(&mut self, hi: u8, lo: u8) -> Result<(), WriteError> {
+		if hi > 0x0f || lo > 0x0f {
+			return Err(WriteError)
+		}
+
+		self.try_write_u8(hi << 4 | lo)
+	}
+
+	/// Writes two nibbles into one byte, `hi` are the upper four bits.
+	///
+	/// ## Panics
+	/// If a nibble is bigger than 15 or there isn't enough space left.
+	#[track_caller]
+	fn write_nibbles(&mut self, hi: u8, lo: u8) {
+		self.try_write_nibbles(hi, lo).expect("failed to write nibbles")
+	}
+
+	/// Try to write a number smaller than 100 BCD encoded.
+	///
+	/// ## Fails
+	/// If the number is bigger than 99 or there isn't enough space left,
+	/// in which case nothing is written.
+	fn try_write_bcd_u8(&mut self, num: u8) -> Result<(), WriteError> {
+		if num > 99 {
+			return Err(WriteError)
+		}
+
+		self.try_write_nibbles(num / 10, num % 10)
+	}
+
+	/// Writes a number smaller than 100 BCD encoded.
+	///
+	/// ## Panics
+	/// If the number is bigger than 99 or there isn't enough space left.
+	#[track_caller]
+	fn write_bcd_u8(&mut self, num: u8) {
+		self.try_write_bcd_u8(num).expect("failed to write bcd")
+	}
+
 	write_fn!(write_u8, try_write_u8, u8);
 	write_fn!(write_u16, try_write_u16, u16);
 	write_fn!(write_u32, try_write_u32, u32);
@@ -123,6 +504,175 @@ pub trait BytesWrite {
 
 	write_le_fn!(write_le_f32, try_write_le_f32, f32);
 	write_le_fn!(write_le_f64, try_write_le_f64, f64);
+
+	write_slice_fn!(write_f32_slice, try_write_f32_slice, f32, to_be_bytes, "big-endian");
+	write_slice_fn!(write_f64_slice, try_write_f64_slice, f64, to_be_bytes, "big-endian");
+
+	write_slice_fn!(write_le_f32_slice, try_write_le_f32_slice, f32, to_le_bytes, "little-endian");
+	write_slice_fn!(write_le_f64_slice, try_write_le_f64_slice, f64, to_le_bytes, "little-endian");
+
+	/// Try to write a LEB128 encoded `u64`.
+	#[inline]
+	fn try_write_var_u64(&mut self, num: u64) -> Result<(), WriteError> {
+		let mut buf = [0u8; 10];
+		let len = encode_var_u64(num, &mut buf);
+		self.try_write(&buf[..len])
+	}
+
+	/// Writes a LEB128 encoded `u64`.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[inline]
+	#[track_caller]
+	fn write_var_u64(&mut self, num: u64) {
+		self.try_write_var_u64(num).expect("failed to write")
+	}
+
+	/// Try to write the length of the slice LEB128 encoded followed
+	/// by the slice.
+	///
+	/// If this fails nothing should be written.
+	fn try_write_var_prefixed(
+		&mut self,
+		slice: impl AsRef<[u8]>
+	) -> Result<(), WriteError> {
+		let slice = slice.as_ref();
+		let mut buf = [0u8; 10];
+		let len = encode_var_u64(slice.len() as u64, &mut buf);
+
+		self.try_write_vectored(&[&buf[..len], slice])
+	}
+
+	/// Writes the length of the slice LEB128 encoded followed by the slice.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[track_caller]
+	fn write_var_prefixed(&mut self, slice: impl AsRef<[u8]>) {
+		self.try_write_var_prefixed(slice).expect("failed to write")
+	}
+
+	/// Try to write the raw bits of an `f32` in big-endian.
+	#[inline]
+	fn try_write_f32_bits(&mut self, bits: u32) -> Result<(), WriteError> {
+		self.try_write_u32(bits)
+	}
+
+	/// Writes the raw bits of an `f32` in big-endian.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[inline]
+	#[track_caller]
+	fn write_f32_bits(&mut self, bits: u32) {
+		self.try_write_f32_bits(bits).expect("failed to write")
+	}
+
+	/// Try to write the raw bits of an `f64` in big-endian.
+	#[inline]
+	fn try_write_f64_bits(&mut self, bits: u64) -> Result<(), WriteError> {
+		self.try_write_u64(bits)
+	}
+
+	/// Writes the raw bits of an `f64` in big-endian.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left.
+	#[inline]
+	#[track_caller]
+	fn write_f64_bits(&mut self, bits: u64) {
+		self.try_write_f64_bits(bits).expect("failed to write")
+	}
+
+	/// Try to write `num` multiplied by `10^scale` and rounded as an `i64`
+	/// in big-endian.
+	///
+	/// ## Precision
+	/// An `f64` cannot represent every scaled value exactly.
+	///
+	/// ## Fails
+	/// If there aren't enough remaining bytes left, `scale` exceeds
+	/// `i32::MAX` or the scaled value is not finite or outside of the
+	/// `i64` range.
+	fn try_write_fixed_i64(
+		&mut self,
+		num: f64,
+		scale: u32
+	) -> Result<(), WriteError> {
+		let scale: i32 = scale.try_into().map_err(|_| WriteError)?;
+		let scaled = (num * 10f64.powi(scale)).round();
+		// i64::MAX as f64 rounds up to 2^63 which is out of range
+		if !scaled.is_finite() ||
+			scaled < i64::MIN as f64 || scaled >= i64::MAX as f64
+		{
+			return Err(WriteError)
+		}
+
+		self.try_write_i64(scaled as i64)
+	}
+
+	/// Writes `num` multiplied by `10^scale` and rounded as an `i64`
+	/// in big-endian.
+	///
+	/// ## Panics
+	/// If there aren't enough remaining bytes left or the value can't be
+	/// scaled, see `try_write_fixed_i64`.
+	#[inline]
+	#[track_caller]
+	fn write_fixed_i64(&mut self, num: f64, scale: u32) {
+		self.try_write_fixed_i64(num, scale).expect("failed to write")
+	}
+
+	/// Try to write a Windows `FILETIME`, the amount of 100 nanosecond
+	/// intervals since 1601-01-01 as a `u64` in little-endian.
+	///
+	/// The time gets rounded down to 100 nanoseconds.
+	///
+	/// ## Fails
+	/// If the time is before 1601 or doesn't fit into a `u64` or if there
+	/// isn't enough space left, in which case nothing is written.
+	fn try_write_filetime(&mut self, time: SystemTime) -> Result<(), WriteError> {
+		let ft = system_time_to_filetime(time).ok_or(WriteError)?;
+		self.try_write_le_u64(ft)
+	}
+
+	/// Writes a Windows `FILETIME`, see `try_write_filetime`.
+	///
+	/// ## Panics
+	/// If the time is before 1601 or doesn't fit into a `u64` or if there
+	/// isn't enough space left.
+	#[track_caller]
+	fn write_filetime(&mut self, time: SystemTime) {
+		self.try_write_filetime(time).expect("failed to write filetime")
+	}
+
+	/// Try to write the amount of milliseconds since 1970-01-01 as an `i64`
+	/// in big-endian, negative values are before 1970.
+	///
+	/// The time gets rounded down to milliseconds.
+	///
+	/// ## Fails
+	/// If the time doesn't fit into an `i64` or if there isn't enough
+	/// space left, in which case nothing is written.
+	fn try_write_unix_millis(
+		&mut self,
+		time: SystemTime
+	) -> Result<(), WriteError> {
+		let ms = system_time_to_unix_millis(time).ok_or(WriteError)?;
+		self.try_write_i64(ms)
+	}
+
+	/// Writes the amount of milliseconds since 1970-01-01, see
+	/// `try_write_unix_millis`.
+	///
+	/// ## Panics
+	/// If the time doesn't fit into an `i64` or if there isn't enough
+	/// space left.
+	#[track_caller]
+	fn write_unix_millis(&mut self, time: SystemTime) {
+		self.try_write_unix_millis(time).expect("failed to write unix millis")
+	}
 }
 
 impl<W: BytesWrite> BytesWrite for &mut W {
@@ -145,4 +695,14 @@ impl<W: BytesWrite> BytesWrite for &mut W {
 	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
 		(**self).try_write(slice)
 	}
+
+	#[inline]
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		(**self).try_write_vectored(slices)
+	}
+
+	#[inline]
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		(**self).write_partial(slice)
+	}
 }
\ No newline at end of file
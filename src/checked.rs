@@ -0,0 +1,249 @@
+use crate::{
+	BytesRead, ReadError,
+	BytesWrite, WriteError,
+	BytesSeek, SeekError,
+	BytesBase
+};
+
+macro_rules! checked_read_fn {
+	($name:ident, $try_name:ident, $type:ident) => (
+		checked_read_fn!($name, $try_name, $type, stringify!($type));
+	);
+	($name:ident, $try_name:ident, $type:ident, $type_str:expr) => {
+		#[inline]
+		#[doc = "Try to read an `"]
+		#[doc = $type_str]
+		#[doc = "`, see `BytesRead::"]
+		#[doc = stringify!($try_name)]
+		#[doc = "`."]
+		pub fn $name(&mut self) -> Result<$type, ReadError> {
+			self.inner.$try_name()
+		}
+	}
+}
+
+macro_rules! checked_write_fn {
+	($name:ident, $try_name:ident, $type:ident) => (
+		checked_write_fn!($name, $try_name, $type, stringify!($type));
+	);
+	($name:ident, $try_name:ident, $type:ident, $type_str:expr) => {
+		#[inline]
+		#[doc = "Try to write an `"]
+		#[doc = $type_str]
+		#[doc = "`, see `BytesWrite::"]
+		#[doc = stringify!($try_name)]
+		#[doc = "`."]
+		pub fn $name(&mut self, num: $type) -> Result<(), WriteError> {
+			self.inner.$try_name(num)
+		}
+	}
+}
+
+/// A wrapper which replaces the panicking read, write and seek methods
+/// with ones returning a `Result`.
+///
+/// Checked does not implement the traits itself so the panicking
+/// methods cannot be called by accident.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{Checked, Bytes};
+/// let mut bytes = Checked::new(Bytes::from(&[0u8, 1, 2][..]));
+/// assert_eq!(bytes.read_u16(), Ok(1));
+/// assert!(bytes.read_u16().is_err());
+/// assert!(bytes.seek(10).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checked<T> {
+	inner: T
+}
+
+impl<T> Checked<T> {
+	/// Creates a new Checked.
+	pub fn new(inner: T) -> Self {
+		Self { inner }
+	}
+
+	/// Returns the inner value as a reference.
+	pub fn inner(&self) -> &T {
+		&self.inner
+	}
+
+	/// Returns the inner value as a mutable reference.
+	pub fn inner_mut(&mut self) -> &mut T {
+		&mut self.inner
+	}
+
+	/// Returns the inner value.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+
+impl<T> Checked<T>
+where T: BytesRead {
+	/// Returns the entire slice.
+	#[inline]
+	pub fn as_slice(&self) -> &[u8] {
+		self.inner.as_slice()
+	}
+
+	/// Returns all remaining bytes.
+	#[inline]
+	pub fn remaining(&self) -> &[u8] {
+		self.inner.remaining()
+	}
+
+	/// Try to read a given length of bytes.
+	#[inline]
+	pub fn read(&mut self, len: usize) -> Result<&[u8], ReadError> {
+		self.inner.try_read(len)
+	}
+
+	/// Tries to read a given length without updating
+	/// the internal position.
+	#[inline]
+	pub fn peek(&self, len: usize) -> Option<&[u8]> {
+		self.inner.peek(len)
+	}
+
+	checked_read_fn!(read_u8, try_read_u8, u8);
+	checked_read_fn!(read_u16, try_read_u16, u16);
+	checked_read_fn!(read_u32, try_read_u32, u32);
+	checked_read_fn!(read_u64, try_read_u64, u64);
+	checked_read_fn!(read_u128, try_read_u128, u128);
+
+	checked_read_fn!(read_i8, try_read_i8, i8);
+	checked_read_fn!(read_i16, try_read_i16, i16);
+	checked_read_fn!(read_i32, try_read_i32, i32);
+	checked_read_fn!(read_i64, try_read_i64, i64);
+	checked_read_fn!(read_i128, try_read_i128, i128);
+
+	checked_read_fn!(read_f32, try_read_f32, f32);
+	checked_read_fn!(read_f64, try_read_f64, f64);
+
+	checked_read_fn!(read_le_u8, try_read_le_u8, u8);
+	checked_read_fn!(read_le_u16, try_read_le_u16, u16);
+	checked_read_fn!(read_le_u32, try_read_le_u32, u32);
+	checked_read_fn!(read_le_u64, try_read_le_u64, u64);
+	checked_read_fn!(read_le_u128, try_read_le_u128, u128);
+
+	checked_read_fn!(read_le_i8, try_read_le_i8, i8);
+	checked_read_fn!(read_le_i16, try_read_le_i16, i16);
+	checked_read_fn!(read_le_i32, try_read_le_i32, i32);
+	checked_read_fn!(read_le_i64, try_read_le_i64, i64);
+	checked_read_fn!(read_le_i128, try_read_le_i128, i128);
+
+	checked_read_fn!(read_le_f32, try_read_le_f32, f32);
+	checked_read_fn!(read_le_f64, try_read_le_f64, f64);
+}
+
+impl<T> Checked<T>
+where T: BytesWrite {
+	/// Try to write a slice.
+	#[inline]
+	pub fn write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
+		self.inner.try_write(slice)
+	}
+
+	checked_write_fn!(write_u8, try_write_u8, u8);
+	checked_write_fn!(write_u16, try_write_u16, u16);
+	checked_write_fn!(write_u32, try_write_u32, u32);
+	checked_write_fn!(write_u64, try_write_u64, u64);
+	checked_write_fn!(write_u128, try_write_u128, u128);
+
+	checked_write_fn!(write_i8, try_write_i8, i8);
+	checked_write_fn!(write_i16, try_write_i16, i16);
+	checked_write_fn!(write_i32, try_write_i32, i32);
+	checked_write_fn!(write_i64, try_write_i64, i64);
+	checked_write_fn!(write_i128, try_write_i128, i128);
+
+	checked_write_fn!(write_f32, try_write_f32, f32);
+	checked_write_fn!(write_f64, try_write_f64, f64);
+
+	checked_write_fn!(write_le_u8, try_write_le_u8, u8);
+	checked_write_fn!(write_le_u16, try_write_le_u16, u16);
+	checked_write_fn!(write_le_u32, try_write_le_u32, u32);
+	checked_write_fn!(write_le_u64, try_write_le_u64, u64);
+	checked_write_fn!(write_le_u128, try_write_le_u128, u128);
+
+	checked_write_fn!(write_le_i8, try_write_le_i8, i8);
+	checked_write_fn!(write_le_i16, try_write_le_i16, i16);
+	checked_write_fn!(write_le_i32, try_write_le_i32, i32);
+	checked_write_fn!(write_le_i64, try_write_le_i64, i64);
+	checked_write_fn!(write_le_i128, try_write_le_i128, i128);
+
+	checked_write_fn!(write_le_f32, try_write_le_f32, f32);
+	checked_write_fn!(write_le_f64, try_write_le_f64, f64);
+}
+
+impl<T> Checked<T>
+where T: BytesSeek {
+	/// Returns the internal position.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.inner.position()
+	}
+
+	/// Try to set the internal position.
+	#[inline]
+	pub fn seek(&mut self, pos: usize) -> Result<(), SeekError> {
+		self.inner.try_seek(pos)
+	}
+
+	/// Try to advance the internal position.
+	#[inline]
+	pub fn advance(&mut self, adv: usize) -> Result<(), SeekError> {
+		self.inner.try_advance(adv)
+	}
+}
+
+impl<T> From<T> for Checked<T> {
+	fn from(inner: T) -> Self {
+		Self::new(inner)
+	}
+}
+
+impl<T> BytesBase for Checked<T>
+where T: BytesBase {
+	type Base = T::Base;
+
+	#[inline]
+	fn base(&self) -> &T::Base {
+		self.inner.base()
+	}
+
+	#[inline]
+	fn base_mut(&mut self) -> &mut T::Base {
+		self.inner.base_mut()
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BytesMut, BytesOwned};
+
+	#[test]
+	fn read() {
+		let mut bytes = Checked::new(BytesOwned::from(vec![0, 1, 2]));
+		assert_eq!(bytes.read_u16(), Ok(1));
+		assert_eq!(bytes.read_u16(), Err(ReadError));
+		assert_eq!(bytes.read_le_u8(), Ok(2));
+		assert_eq!(bytes.read(1), Err(ReadError));
+		assert_eq!(bytes.position(), 3);
+	}
+
+	#[test]
+	fn write() {
+		let mut buf = [0u8; 3];
+		let mut bytes = Checked::new(BytesMut::from(&mut buf[..]));
+		assert_eq!(bytes.seek(4), Err(SeekError { attempted: 4, max: 3 }));
+		assert!(bytes.advance(4).is_err());
+		assert_eq!(bytes.write_u16(1), Ok(()));
+		assert_eq!(bytes.write_u16(2), Err(WriteError));
+		assert_eq!(bytes.write([3]), Ok(()));
+		assert_eq!(bytes.into_inner().as_slice(), &[0, 1, 3]);
+	}
+}
@@ -4,9 +4,14 @@ use crate::{
 	BytesWrite, WriteError,
 	BytesSeek, SeekError, Bytes
 };
-use crate::util::{io_eof, seek_from_to_n_pos, write_or_alloc};
+use crate::util::{
+	io_eof, io_write_zero, seek_from_to_n_pos, write_or_alloc,
+	write_vectored_or_alloc
+};
 
-use std::io;
+use std::{io, fmt};
+use std::rc::Rc;
+use std::sync::Arc;
 
 /// A generic struct implementing BytesRead, BytesWrite and BytesSeek
 /// for different types.
@@ -20,6 +25,41 @@ pub struct Cursor<T> {
 	inner: T
 }
 
+/// A buffer with a fixed length which a `Cursor` can write to.
+///
+/// Implement this for a custom buffer type to get the `BytesWrite` and
+/// `BytesSeek` implementations of `Cursor`, writing past the end fails.
+/// `Vec<u8>` does not implement it since it grows instead.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{Cursor, FixedBuffer, BytesWrite};
+/// #[repr(align(16))]
+/// struct Aligned([u8; 4]);
+///
+/// impl AsRef<[u8]> for Aligned {
+///     fn as_ref(&self) -> &[u8] { &self.0 }
+/// }
+///
+/// impl AsMut<[u8]> for Aligned {
+///     fn as_mut(&mut self) -> &mut [u8] { &mut self.0 }
+/// }
+///
+/// impl FixedBuffer for Aligned {}
+///
+/// let mut cursor = Cursor::new(Aligned([0; 4]));
+/// cursor.write_u16(1);
+/// assert!(cursor.try_write_u32(2).is_err());
+/// assert_eq!(cursor.into_inner().0, [0, 1, 0, 0]);
+/// ```
+pub trait FixedBuffer: AsRef<[u8]> + AsMut<[u8]> {}
+
+impl FixedBuffer for &mut [u8] {}
+
+impl<const L: usize> FixedBuffer for [u8; L] {}
+
+impl FixedBuffer for Box<[u8]> {}
+
 impl<T> Cursor<T> {
 	/// Creates a new Cursor.
 	pub fn new(inner: T) -> Self {
@@ -29,6 +69,18 @@ impl<T> Cursor<T> {
 		}
 	}
 
+	/// Creates a new Cursor with the given position.
+	///
+	/// ## Fails
+	/// If the position is not valid for the inner value,
+	/// see `BytesSeek::try_seek`.
+	pub fn with_position(inner: T, position: usize) -> Result<Self, SeekError>
+	where Self: BytesSeek {
+		let mut cursor = Self::new(inner);
+		cursor.try_seek(position)?;
+		Ok(cursor)
+	}
+
 	/// Returns the inner value as a reference.
 	pub fn inner(&self) -> &T {
 		&self.inner
@@ -55,14 +107,16 @@ where T: AsRef<[u8]> {
 		self.inner.as_ref()
 	}
 
+	/// Returns an empty slice if the position is past the end.
 	#[inline]
 	fn remaining(&self) -> &[u8] {
-		&self.as_slice()[self.position..]
+		self.as_slice().get(self.position..).unwrap_or(&[])
 	}
 
 	#[inline]
 	fn try_read(&mut self, len: usize) -> Result<&[u8], ReadError> {
-		let slice = &self.inner.as_ref()[self.position..].get(..len)
+		let slice = self.inner.as_ref().get(self.position..)
+			.and_then(|rem| rem.get(..len))
 			.ok_or(ReadError)?;
 		self.position += len;
 
@@ -84,7 +138,7 @@ where T: AsRef<[u8]> {
 	}
 }
 
-impl<'a> BytesSeek for Cursor<&'a [u8]> {
+impl BytesSeek for Cursor<&[u8]> {
 	#[inline]
 	fn position(&self) -> usize {
 		self.position
@@ -96,18 +150,18 @@ impl<'a> BytesSeek for Cursor<&'a [u8]> {
 	/// If the position exceeds the slice.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
 		let len = self.inner.len();
-		if self.position + len >= pos {
+		if len >= pos {
 			self.position = pos;
 			Ok(())
 		} else {
-			Err(SeekError(len))
+			Err(SeekError { attempted: pos, max: len })
 		}
 	}
 }
 
 
 
-impl<'a> io::Seek for Cursor<&'a [u8]> {
+impl io::Seek for Cursor<&[u8]> {
 	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
 		let n_pos = seek_from_to_n_pos(self.inner.len(), self.position, pos)?;
 
@@ -118,17 +172,18 @@ impl<'a> io::Seek for Cursor<&'a [u8]> {
 }
 
 
-impl<'a> BytesWrite for Cursor<&'a mut [u8]> {
+impl<T> BytesWrite for Cursor<T>
+where T: FixedBuffer {
 	fn as_mut(&mut self) -> &mut [u8] {
-		self.inner
+		self.inner.as_mut()
 	}
 
 	fn as_bytes(&self) -> Bytes<'_> {
-		Bytes::new(0, &*self.inner)
+		Bytes::new(0, self.inner.as_ref())
 	}
 
 	fn remaining_mut(&mut self) -> &mut [u8] {
-		&mut self.inner[self.position..]
+		&mut self.inner.as_mut()[self.position..]
 	}
 
 	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
@@ -142,19 +197,28 @@ impl<'a> BytesWrite for Cursor<&'a mut [u8]> {
 	}
 }
 
-impl<'a> io::Write for Cursor<&'a mut [u8]> {
+impl<T> io::Write for Cursor<T>
+where T: FixedBuffer {
 	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
 		self.try_write(buf)
 			.map_err(io_eof)?;
 		Ok(buf.len())
 	}
 
+	/// Writes the entire buffer at once, if it doesn't fit nothing
+	/// is written and an error with the kind `WriteZero` is returned.
+	fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+		self.try_write(buf)
+			.map_err(io_write_zero)
+	}
+
 	fn flush(&mut self) -> io::Result<()> {
 		Ok(())
 	}
 }
 
-impl<'a> BytesSeek for Cursor<&'a mut [u8]> {
+impl<T> BytesSeek for Cursor<T>
+where T: FixedBuffer {
 	fn position(&self) -> usize {
 		self.position
 	}
@@ -164,19 +228,21 @@ impl<'a> BytesSeek for Cursor<&'a mut [u8]> {
 	/// ## Fails
 	/// If the position exceeds the slice.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
-		let len = self.inner.len();
-		if self.position + len >= pos {
+		let len = self.inner.as_ref().len();
+		if len >= pos {
 			self.position = pos;
 			Ok(())
 		} else {
-			Err(SeekError(len))
+			Err(SeekError { attempted: pos, max: len })
 		}
 	}
 }
 
-impl<'a> io::Seek for Cursor<&'a mut [u8]> {
+impl<T> io::Seek for Cursor<T>
+where T: FixedBuffer {
 	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
-		let n_pos = seek_from_to_n_pos(self.inner.len(), self.position, pos)?;
+		let len = self.inner.as_ref().len();
+		let n_pos = seek_from_to_n_pos(len, self.position, pos)?;
 
 		self.try_seek(n_pos)
 			.map(|_| n_pos as u64)
@@ -185,31 +251,58 @@ impl<'a> io::Seek for Cursor<&'a mut [u8]> {
 }
 
 
-impl<const L: usize> BytesWrite for Cursor<[u8; L]> {
+impl BytesWrite for Cursor<&mut Vec<u8>> {
 	fn as_mut(&mut self) -> &mut [u8] {
-		&mut self.inner
+		self.inner
 	}
 
 	fn as_bytes(&self) -> Bytes<'_> {
-		Bytes::new(0, &self.inner)
+		Bytes::new(0, self.inner)
 	}
 
+	/// Returns the remaining mutable slice.
+	/// 
+	/// If an empty slice is returned, this does not mean
+	/// you can't write anymore.
 	fn remaining_mut(&mut self) -> &mut [u8] {
-		&mut self.inner[self.position..]
+		self.inner.get_mut(self.position..).unwrap_or(&mut [])
 	}
 
+	/// Write a slice. Allocates more space if the slice is
+	/// bigger than the `Vec`.
 	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
-		let slice = slice.as_ref();
-		self.remaining_mut().get_mut(..slice.len())
-			.ok_or(WriteError)?
-			.copy_from_slice(slice);
-		self.position += slice.len();
+		self.position = write_or_alloc(
+			self.inner,
+			self.position,
+			slice.as_ref()
+		);
 
 		Ok(())
 	}
+
+	/// Writes all slices, allocating the needed space only once.
+	///
+	/// ## Fails
+	/// If the end position would overflow, in which case nothing is
+	/// written.
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		self.position = write_vectored_or_alloc(
+			self.inner,
+			self.position,
+			slices
+		).ok_or(WriteError)?;
+
+		Ok(())
+	}
+
+	/// Writes the entire slice, allocating more space if needed.
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		self.write(slice);
+		slice.len()
+	}
 }
 
-impl<const L: usize> io::Write for Cursor<[u8; L]> {
+impl io::Write for Cursor<&mut Vec<u8>> {
 	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
 		self.try_write(buf)
 			.map_err(io_eof)?;
@@ -221,38 +314,34 @@ impl<const L: usize> io::Write for Cursor<[u8; L]> {
 	}
 }
 
-impl<const L: usize> BytesSeek for Cursor<[u8; L]> {
+impl BytesSeek for Cursor<&mut Vec<u8>> {
 	fn position(&self) -> usize {
 		self.position
 	}
 
 	/// Sets the internal position.
-	/// 
-	/// ## Fails
-	/// If the position exceeds the slice.
+	///
+	/// The position may be past the end of the `Vec`, the gap only
+	/// gets filled with zeros once something is written.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
-		let len = self.inner.len();
-		if self.position + len >= pos {
-			self.position = pos;
-			Ok(())
-		} else {
-			Err(SeekError(len))
-		}
+		self.position = pos;
+		Ok(())
 	}
 }
 
-impl<const L: usize> io::Seek for Cursor<[u8; L]> {
+impl io::Seek for Cursor<&mut Vec<u8>> {
+	/// Seeks to an offset, seeking past the end is allowed and does
+	/// not allocate until something gets written.
 	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
 		let n_pos = seek_from_to_n_pos(self.inner.len(), self.position, pos)?;
+		self.position = n_pos;
 
-		self.try_seek(n_pos)
-			.map(|_| n_pos as u64)
-			.map_err(io_eof)
+		Ok(n_pos as u64)
 	}
 }
 
 
-impl BytesWrite for Cursor<&mut Vec<u8>> {
+impl BytesWrite for Cursor<Vec<u8>> {
 	fn as_mut(&mut self) -> &mut [u8] {
 		&mut self.inner
 	}
@@ -266,23 +355,44 @@ impl BytesWrite for Cursor<&mut Vec<u8>> {
 	/// If an empty slice is returned, this does not mean
 	/// you can't write anymore.
 	fn remaining_mut(&mut self) -> &mut [u8] {
-		&mut self.inner[self.position..]
+		self.inner.get_mut(self.position..).unwrap_or(&mut [])
 	}
 
 	/// Write a slice. Allocates more space if the slice is
 	/// bigger than the `Vec`.
 	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
 		self.position = write_or_alloc(
-			self.inner,
+			&mut self.inner,
 			self.position,
 			slice.as_ref()
 		);
 
 		Ok(())
 	}
+
+	/// Writes all slices, allocating the needed space only once.
+	///
+	/// ## Fails
+	/// If the end position would overflow, in which case nothing is
+	/// written.
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		self.position = write_vectored_or_alloc(
+			&mut self.inner,
+			self.position,
+			slices
+		).ok_or(WriteError)?;
+
+		Ok(())
+	}
+
+	/// Writes the entire slice, allocating more space if needed.
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		self.write(slice);
+		slice.len()
+	}
 }
 
-impl io::Write for Cursor<&mut Vec<u8>> {
+impl io::Write for Cursor<Vec<u8>> {
 	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
 		self.try_write(buf)
 			.map_err(io_eof)?;
@@ -294,96 +404,92 @@ impl io::Write for Cursor<&mut Vec<u8>> {
 	}
 }
 
-impl BytesSeek for Cursor<&mut Vec<u8>> {
+impl fmt::Write for Cursor<Vec<u8>> {
+	/// Writes the string as UTF-8 at the current position.
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.try_write(s.as_bytes())
+			.map_err(|_| fmt::Error)
+	}
+}
+
+impl BytesSeek for Cursor<Vec<u8>> {
 	fn position(&self) -> usize {
 		self.position
 	}
 
-	/// Sets the internal position, allocating more space
-	/// if the position is bigger than the `Vec`.
+	/// Sets the internal position.
+	///
+	/// The position may be past the end of the `Vec`, the gap only
+	/// gets filled with zeros once something is written.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
 		self.position = pos;
-		let n_len = self.position;
-		if self.inner.len() < n_len {
-			self.inner.resize(n_len, 0u8);
-		}
-
 		Ok(())
 	}
 }
 
-impl io::Seek for Cursor<&mut Vec<u8>> {
+impl io::Seek for Cursor<Vec<u8>> {
+	/// Seeks to an offset, seeking past the end is allowed and does
+	/// not allocate until something gets written.
 	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
 		let n_pos = seek_from_to_n_pos(self.inner.len(), self.position, pos)?;
+		self.position = n_pos;
 
-		self.try_seek(n_pos)
-			.map(|_| n_pos as u64)
-			.map_err(io_eof)
+		Ok(n_pos as u64)
 	}
 }
 
 
-impl BytesWrite for Cursor<Vec<u8>> {
-	fn as_mut(&mut self) -> &mut [u8] {
-		&mut self.inner
-	}
-
-	fn as_bytes(&self) -> Bytes<'_> {
-		Bytes::new(0, &self.inner)
+impl BytesSeek for Cursor<Rc<[u8]>> {
+	fn position(&self) -> usize {
+		self.position
 	}
 
-	/// Returns the remaining mutable slice.
+	/// Sets the internal position.
 	/// 
-	/// If an empty slice is returned, this does not mean
-	/// you can't write anymore.
-	fn remaining_mut(&mut self) -> &mut [u8] {
-		&mut self.inner[self.position..]
-	}
-
-	/// Write a slice. Allocates more space if the slice is
-	/// bigger than the `Vec`.
-	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
-		self.position = write_or_alloc(
-			&mut self.inner,
-			self.position,
-			slice.as_ref()
-		);
-
-		Ok(())
+	/// ## Fails
+	/// If the position exceeds the slice.
+	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
+		let len = self.inner.len();
+		if len >= pos {
+			self.position = pos;
+			Ok(())
+		} else {
+			Err(SeekError { attempted: pos, max: len })
+		}
 	}
 }
 
-impl io::Write for Cursor<Vec<u8>> {
-	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-		self.try_write(buf)
-			.map_err(io_eof)?;
-		Ok(buf.len())
-	}
+impl io::Seek for Cursor<Rc<[u8]>> {
+	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+		let n_pos = seek_from_to_n_pos(self.inner.len(), self.position, pos)?;
 
-	fn flush(&mut self) -> io::Result<()> {
-		Ok(())
+		self.try_seek(n_pos)
+			.map(|_| n_pos as u64)
+			.map_err(io_eof)
 	}
 }
 
-impl BytesSeek for Cursor<Vec<u8>> {
+impl BytesSeek for Cursor<Arc<[u8]>> {
 	fn position(&self) -> usize {
 		self.position
 	}
 
-	/// Sets the internal position, allocating more space
-	/// if the position is bigger than the `Vec`.
+	/// Sets the internal position.
+	/// 
+	/// ## Fails
+	/// If the position exceeds the slice.
 	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
-		self.position = pos;
-		let n_len = self.position;
-		if self.inner.len() < n_len {
-			self.inner.resize(n_len, 0u8);
+		let len = self.inner.len();
+		if len >= pos {
+			self.position = pos;
+			Ok(())
+		} else {
+			Err(SeekError { attempted: pos, max: len })
 		}
-
-		Ok(())
 	}
 }
 
-impl io::Seek for Cursor<Vec<u8>> {
+impl io::Seek for Cursor<Arc<[u8]>> {
 	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
 		let n_pos = seek_from_to_n_pos(self.inner.len(), self.position, pos)?;
 
@@ -391,4 +497,57 @@ impl io::Seek for Cursor<Vec<u8>> {
 			.map(|_| n_pos as u64)
 			.map_err(io_eof)
 	}
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fmt_write() {
+		use std::fmt::Write;
+
+		let mut cursor = Cursor::new(Vec::new());
+		writeln!(cursor, "{}", -1i8).unwrap();
+		cursor.write_char('ä').unwrap();
+		assert_eq!(cursor.inner(), "-1\nä".as_bytes());
+	}
+
+	#[test]
+	fn with_position() {
+		let cursor = Cursor::with_position(&[1u8, 2, 3][..], 2).unwrap();
+		assert_eq!(cursor.remaining(), &[3]);
+		assert_eq!(
+			Cursor::with_position(&[1u8, 2, 3][..], 4).unwrap_err(),
+			SeekError { attempted: 4, max: 3 }
+		);
+
+		let cursor = Cursor::with_position(vec![1u8], 3).unwrap();
+		assert_eq!(cursor.position(), 3);
+	}
+
+	#[test]
+	fn fixed_buffer() {
+		let mut cursor = Cursor::new(vec![0u8; 3].into_boxed_slice());
+		cursor.write_u16(1);
+		assert!(cursor.try_write_u16(2).is_err());
+		assert!(cursor.try_seek(4).is_err());
+		assert_eq!(&*cursor.into_inner(), &[0, 1, 0]);
+	}
+
+
+	#[test]
+	fn write_vectored_overflow() {
+		let mut cursor = Cursor::new(vec![1u8]);
+		cursor.seek(usize::MAX - 1);
+		assert!(cursor.try_write_vectored(&[&[1], &[2]]).is_err());
+		assert_eq!(cursor.position(), usize::MAX - 1);
+		assert_eq!(cursor.inner(), &[1]);
+
+		let mut vec = vec![];
+		let mut cursor = Cursor::new(&mut vec);
+		cursor.seek(usize::MAX);
+		assert!(cursor.try_write_vectored(&[&[1]]).is_err());
+	}
+}
@@ -0,0 +1,8 @@
+/// The byte order used to read or write a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+	/// Most significant byte first.
+	Big,
+	/// Least significant byte first.
+	Little
+}
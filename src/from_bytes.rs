@@ -0,0 +1,57 @@
+
+use crate::{BytesRead, ReadError};
+
+macro_rules! from_bytes_impl {
+	($($type:ident => $try_name:ident),*) => ($(
+		impl FromBytes for $type {
+			/// Reads the number in big-endian.
+			#[inline]
+			fn from_bytes<R: BytesRead>(r: &mut R) -> Result<Self, ReadError> {
+				r.$try_name()
+			}
+		}
+	)*)
+}
+
+/// A type that can be read from a `BytesRead`.
+///
+/// Numbers are read in big-endian.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{Bytes, BytesRead, FromBytes, ReadError};
+/// struct Header {
+///     version: u16,
+///     len: u32
+/// }
+///
+/// impl FromBytes for Header {
+///     fn from_bytes<R: BytesRead>(r: &mut R) -> Result<Self, ReadError> {
+///         Ok(Self {
+///             version: r.try_read_typed()?,
+///             len: r.try_read_typed()?
+///         })
+///     }
+/// }
+///
+/// let mut bytes = Bytes::from_array(&[0, 1, 0, 0, 0, 4]);
+/// let header: Header = bytes.read_typed();
+/// assert_eq!(header.version, 1);
+/// assert_eq!(header.len, 4);
+/// ```
+pub trait FromBytes: Sized {
+	/// Reads `Self` from `r`.
+	///
+	/// ## Fails
+	/// If there aren't enough bytes left or the bytes are not valid,
+	/// on failure some bytes may have been read.
+	fn from_bytes<R: BytesRead>(r: &mut R) -> Result<Self, ReadError>;
+}
+
+from_bytes_impl!(
+	u8 => try_read_u8, u16 => try_read_u16, u32 => try_read_u32,
+	u64 => try_read_u64, u128 => try_read_u128,
+	i8 => try_read_i8, i16 => try_read_i16, i32 => try_read_i32,
+	i64 => try_read_i64, i128 => try_read_i128,
+	f32 => try_read_f32, f64 => try_read_f64
+);
@@ -0,0 +1,84 @@
+
+use crate::{
+	BytesRead, ReadError,
+	BytesWrite, WriteError,
+	FromBytes, ToBytes
+};
+
+/// Used by the `FromBytes` derive for `len_prefix` fields.
+#[doc(hidden)]
+pub trait ReadLenPrefixed: Sized {
+	fn try_read_len_prefixed<R: BytesRead>(
+		r: &mut R,
+		len: usize
+	) -> Result<Self, ReadError>;
+}
+
+impl<T: FromBytes> ReadLenPrefixed for Vec<T> {
+	fn try_read_len_prefixed<R: BytesRead>(
+		r: &mut R,
+		len: usize
+	) -> Result<Self, ReadError> {
+		// don't trust len to preallocate
+		let mut vec = Vec::with_capacity(len.min(r.remaining().len()));
+		for _ in 0..len {
+			vec.push(T::from_bytes(r)?);
+		}
+
+		Ok(vec)
+	}
+}
+
+impl ReadLenPrefixed for String {
+	fn try_read_len_prefixed<R: BytesRead>(
+		r: &mut R,
+		len: usize
+	) -> Result<Self, ReadError> {
+		let slice = r.peek(len).ok_or(ReadError)?;
+		let s = std::str::from_utf8(slice).map_err(|_| ReadError)?.to_string();
+		r.try_read(len)?;
+
+		Ok(s)
+	}
+}
+
+/// Used by the `ToBytes` derive for `len_prefix` fields.
+#[doc(hidden)]
+pub trait WriteLenPrefixed {
+	fn prefix_len(&self) -> usize;
+
+	fn try_write_len_prefixed<W: BytesWrite>(
+		&self,
+		w: &mut W
+	) -> Result<(), WriteError>;
+}
+
+impl<T: ToBytes> WriteLenPrefixed for Vec<T> {
+	fn prefix_len(&self) -> usize {
+		self.len()
+	}
+
+	fn try_write_len_prefixed<W: BytesWrite>(
+		&self,
+		w: &mut W
+	) -> Result<(), WriteError> {
+		for item in self {
+			item.to_bytes(w)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl WriteLenPrefixed for String {
+	fn prefix_len(&self) -> usize {
+		self.len()
+	}
+
+	fn try_write_len_prefixed<W: BytesWrite>(
+		&self,
+		w: &mut W
+	) -> Result<(), WriteError> {
+		w.try_write(self.as_bytes())
+	}
+}
@@ -30,14 +30,30 @@
 //! assert_eq!(3, slice.remaining().len());
 //! ```
 
-mod util;
+pub mod util;
+
+mod macros;
+#[doc(hidden)]
+pub use macros::StructField as __StructField;
+
+mod endian;
+pub use endian::Endian;
 
 mod cursor;
-pub use cursor::Cursor;
+pub use cursor::{Cursor, FixedBuffer};
 
 mod offset;
 pub use offset::Offset;
 
+mod limited_reader;
+pub use limited_reader::LimitedReader;
+
+mod budget_reader;
+pub use budget_reader::BudgetReader;
+
+mod checked;
+pub use checked::Checked;
+
 mod bytes;
 pub use bytes::Bytes;
 
@@ -45,16 +61,49 @@ mod bytes_mut;
 pub use bytes_mut::BytesMut;
 
 mod bytes_owned;
-pub use bytes_owned::BytesOwned;
+pub use bytes_owned::{BytesOwned, WriteReport};
 
 mod bytes_array;
 pub use bytes_array::BytesArray;
 
+mod vec_writer;
+pub use vec_writer::VecWriter;
+
+mod secret_bytes;
+pub use secret_bytes::SecretBytes;
+
 mod bytes_read;
-pub use bytes_read::{BytesRead, ReadError, BytesReadRef};
+pub use bytes_read::{
+	BytesRead, ReadError, StrReadError, NeedError, ExtendedLen, BytesReadRef
+};
 
 mod bytes_write;
 pub use bytes_write::{BytesWrite, WriteError};
 
+mod from_bytes;
+pub use from_bytes::FromBytes;
+
+mod to_bytes;
+pub use to_bytes::ToBytes;
+
+mod len_prefixed;
+#[doc(hidden)]
+pub use len_prefixed::{
+	ReadLenPrefixed as __ReadLenPrefixed,
+	WriteLenPrefixed as __WriteLenPrefixed
+};
+
+#[cfg(feature = "derive")]
+pub use simple_bytes_derive::{FromBytes, ToBytes};
+
+mod split_iter;
+pub use split_iter::SplitIter;
+
 mod bytes_seek;
-pub use bytes_seek::{BytesSeek, SeekError};
\ No newline at end of file
+pub use bytes_seek::{BytesSeek, SeekError, SeekFromError};
+
+mod write_guard;
+pub use write_guard::WriteGuard;
+
+mod bytes_base;
+pub use bytes_base::BytesBase;
\ No newline at end of file
@@ -0,0 +1,230 @@
+use crate::{BytesRead, ReadError, BytesReadRef, BytesSeek, SeekError, BytesBase};
+
+/// A reader which refuses any single read longer than a maximum
+/// length, regardless of how many bytes remain.
+///
+/// Useful when lengths come from untrusted input. Note that this also
+/// applies to the number reads, so `max_len` should be at least 16 if
+/// `read_u128` is used.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{LimitedReader, Bytes, BytesRead};
+/// let mut bytes = LimitedReader::new(Bytes::from_array(&[0u8; 8]), 4);
+/// assert!(bytes.try_read(4).is_ok());
+/// assert!(bytes.try_read(5).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LimitedReader<T> {
+	max_len: usize,
+	inner: T
+}
+
+impl<T> LimitedReader<T> {
+	/// Creates a new LimitedReader.
+	pub fn new(inner: T, max_len: usize) -> Self {
+		Self { max_len, inner }
+	}
+
+	/// Returns the maximum length of a single read.
+	pub fn max_len(&self) -> usize {
+		self.max_len
+	}
+
+	/// Returns the inner value as a reference.
+	pub fn inner(&self) -> &T {
+		&self.inner
+	}
+
+	/// Returns the inner value as a mutable reference.
+	pub fn inner_mut(&mut self) -> &mut T {
+		&mut self.inner
+	}
+
+	/// Returns the inner value, discarding the limit.
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+
+impl<T> BytesRead for LimitedReader<T>
+where T: BytesRead {
+	#[inline]
+	fn as_slice(&self) -> &[u8] {
+		self.inner.as_slice()
+	}
+
+	#[inline]
+	fn remaining(&self) -> &[u8] {
+		self.inner.remaining()
+	}
+
+	/// Try to read a given length of bytes.
+	///
+	/// ## Fails
+	/// If len exceeds `max_len` or `self.remaining().len()`.
+	#[inline]
+	fn try_read(&mut self, len: usize) -> Result<&[u8], ReadError> {
+		if len > self.max_len {
+			return Err(ReadError)
+		}
+
+		self.inner.try_read(len)
+	}
+
+	#[inline]
+	fn peek(&self, len: usize) -> Option<&[u8]> {
+		if len > self.max_len {
+			return None
+		}
+
+		self.inner.peek(len)
+	}
+}
+
+impl<'a, T> BytesReadRef<'a> for LimitedReader<T>
+where T: BytesReadRef<'a> {
+	#[inline]
+	fn as_slice_ref(&self) -> &'a [u8] {
+		self.inner.as_slice_ref()
+	}
+
+	#[inline]
+	fn remaining_ref(&self) -> &'a [u8] {
+		self.inner.remaining_ref()
+	}
+
+	#[inline]
+	fn try_read_ref(&mut self, len: usize) -> Result<&'a [u8], ReadError> {
+		if len > self.max_len {
+			return Err(ReadError)
+		}
+
+		self.inner.try_read_ref(len)
+	}
+
+	#[inline]
+	fn peek_ref(&self, len: usize) -> Option<&'a [u8]> {
+		if len > self.max_len {
+			return None
+		}
+
+		self.inner.peek_ref(len)
+	}
+}
+
+impl<T> BytesSeek for LimitedReader<T>
+where T: BytesSeek {
+	fn position(&self) -> usize {
+		self.inner.position()
+	}
+
+	fn absolute_position(&self) -> usize {
+		self.inner.absolute_position()
+	}
+
+	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
+		self.inner.try_seek(pos)
+	}
+}
+
+impl<T> From<(T, usize)> for LimitedReader<T> {
+	/// Same as `LimitedReader::new`.
+	fn from((inner, max_len): (T, usize)) -> Self {
+		Self::new(inner, max_len)
+	}
+}
+
+impl<T> BytesBase for LimitedReader<T>
+where T: BytesBase {
+	type Base = T::Base;
+
+	#[inline]
+	fn base(&self) -> &T::Base {
+		self.inner.base()
+	}
+
+	#[inline]
+	fn base_mut(&mut self) -> &mut T::Base {
+		self.inner.base_mut()
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Bytes;
+
+	#[test]
+	fn read() {
+		let bytes: Vec<u8> = (0..20).collect();
+		let mut bytes = LimitedReader::new(Bytes::from(bytes.as_slice()), 4);
+		assert_eq!(bytes.read_u32(), 0x00010203);
+		assert!(bytes.try_read_u64().is_err());
+		assert!(bytes.peek(5).is_none());
+		assert!(bytes.try_read_ref(5).is_err());
+		assert_eq!(bytes.position(), 4);
+		assert_eq!(bytes.read_ref(4), &[4, 5, 6, 7]);
+
+		bytes.seek(18);
+		assert!(bytes.try_read(4).is_err());
+		assert_eq!(bytes.read(2), &[18, 19]);
+	}
+
+	#[test]
+	fn read_remaining_refused() {
+		let mut bytes = LimitedReader::new(Bytes::from(&[1u8, 2, 3, 4][..]), 2);
+		assert!(bytes.try_read_remaining().is_err());
+		assert!(bytes.try_read_len_or_rest(None).is_err());
+		assert_eq!(bytes.remaining(), &[1, 2, 3, 4]);
+
+		bytes.seek(2);
+		assert_eq!(bytes.read_remaining(), &[3, 4]);
+		assert_eq!(bytes.read_remaining(), &[]);
+	}
+
+	#[test]
+	fn read_or_need_refused() {
+		use crate::NeedError;
+
+		let mut bytes = LimitedReader::new(Bytes::from(&[1u8, 2, 3][..]), 2);
+		assert_eq!(bytes.try_read_or_need(3), Err(NeedError::Refused));
+		assert_eq!(
+			bytes.try_read_or_need(4),
+			Err(NeedError::Truncated { needed: 1 })
+		);
+	}
+
+	#[test]
+	fn skip_leading_limited() {
+		let mut bytes = LimitedReader::new(Bytes::from(&[0u8, 0, 0, 1][..]), 2);
+		assert_eq!(bytes.skip_leading(0), 3);
+		assert_eq!(bytes.remaining(), &[1]);
+	}
+
+
+	#[test]
+	fn read_str_refused() {
+		use crate::StrReadError;
+
+		let mut bytes = LimitedReader::new(Bytes::from(&[2u8, b'a', b'b'][..]), 2);
+		assert_eq!(bytes.try_read_str_u8(), Err(StrReadError::Refused));
+		assert_eq!(bytes.try_read_remaining_str(), Err(StrReadError::Refused));
+		assert_eq!(
+			bytes.try_read_remaining_str_ref(),
+			Err(StrReadError::Refused)
+		);
+		assert_eq!(bytes.remaining(), &[2, b'a', b'b']);
+	}
+
+
+	#[test]
+	fn read_kv_map_refused() {
+		let data = [0u8, 0, 0, 1, 0, 0, 0, 1, b'k', 0, 0, 0, 1, b'v'];
+		let mut bytes = LimitedReader::new(Bytes::from(&data[..]), 8);
+		assert!(bytes.try_read_kv_map_u32().is_err());
+		assert!(bytes.try_read_kv_map_u32_ref().is_err());
+		assert_eq!(bytes.remaining().len(), data.len());
+	}
+}
@@ -0,0 +1,200 @@
+use crate::{BytesRead, ReadError, BytesWrite, WriteError};
+
+/// Writes a list of numbers, either in big-endian (`be`) or
+/// little-endian (`le`).
+///
+/// Evaluates to `Result<(), WriteError>`, if a write fails the position
+/// gets restored. The writer needs to implement `BytesWrite` and
+/// `BytesSeek` and be a mutable place, use `*w` for a `&mut` reference.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{write_struct, BytesOwned, BytesRead};
+/// let mut bytes = BytesOwned::new();
+/// write_struct!(bytes, be: {
+///     magic: u32 = 0xCAFE,
+///     version: u16 = 1,
+///     flags: u8 = 0
+/// }).unwrap();
+/// assert_eq!(bytes.as_slice(), &[0, 0, 0xCA, 0xFE, 0, 1, 0]);
+/// ```
+#[macro_export]
+macro_rules! write_struct {
+	($w:expr, be: { $($field:ident: $ty:ty = $val:expr),* $(,)? }) => (
+		$crate::write_struct!(@write $w, try_write_be, { $($field: $ty = $val),* })
+	);
+	($w:expr, le: { $($field:ident: $ty:ty = $val:expr),* $(,)? }) => (
+		$crate::write_struct!(@write $w, try_write_le, { $($field: $ty = $val),* })
+	);
+	(@write $w:expr, $method:ident, { $($field:ident: $ty:ty = $val:expr),* }) => ({
+		let w = &mut $w;
+		let start = $crate::BytesSeek::position(w);
+		let res = (|| -> ::core::result::Result<(), $crate::WriteError> {
+			$(
+				let $field: $ty = $val;
+				$crate::__StructField::$method($field, &mut *w)?;
+			)*
+			Ok(())
+		})();
+		if res.is_err() {
+			$crate::BytesSeek::seek(w, start);
+		}
+		res
+	})
+}
+
+/// Reads a list of numbers into a struct, either in big-endian (`be`) or
+/// little-endian (`le`).
+///
+/// Evaluates to `Result<Struct, ReadError>`, if a read fails the position
+/// gets restored. The reader needs to implement `BytesRead` and
+/// `BytesSeek` and be a mutable place, use `*r` for a `&mut` reference.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{read_struct, Bytes};
+/// struct Header {
+///     magic: u32,
+///     version: u16
+/// }
+///
+/// let mut bytes = Bytes::from_array(&[0, 0, 0xCA, 0xFE, 0, 1]);
+/// let header = read_struct!(bytes, be: Header {
+///     magic: u32,
+///     version: u16
+/// }).unwrap();
+/// assert_eq!(header.magic, 0xCAFE);
+/// assert_eq!(header.version, 1);
+/// ```
+#[macro_export]
+macro_rules! read_struct {
+	($r:expr, be: $name:ident { $($field:ident: $ty:ty),* $(,)? }) => (
+		$crate::read_struct!(@read $r, try_read_be, $name { $($field: $ty),* })
+	);
+	($r:expr, le: $name:ident { $($field:ident: $ty:ty),* $(,)? }) => (
+		$crate::read_struct!(@read $r, try_read_le, $name { $($field: $ty),* })
+	);
+	(@read $r:expr, $method:ident, $name:ident { $($field:ident: $ty:ty),* }) => ({
+		let r = &mut $r;
+		let start = $crate::BytesSeek::position(r);
+		let res = (|| -> ::core::result::Result<_, $crate::ReadError> {
+			Ok($name {
+				$(
+					$field: <$ty as $crate::__StructField>::$method(&mut *r)?
+				),*
+			})
+		})();
+		if res.is_err() {
+			$crate::BytesSeek::seek(r, start);
+		}
+		res
+	})
+}
+
+macro_rules! struct_field_impl {
+	($($type:ident),*) => ($(
+		impl StructField for $type {
+			#[inline]
+			fn try_read_be<R: BytesRead>(r: &mut R) -> Result<Self, ReadError> {
+				r.try_read(std::mem::size_of::<Self>())?
+					.try_into()
+					.map($type::from_be_bytes)
+					.map_err(|_| ReadError)
+			}
+
+			#[inline]
+			fn try_read_le<R: BytesRead>(r: &mut R) -> Result<Self, ReadError> {
+				r.try_read(std::mem::size_of::<Self>())?
+					.try_into()
+					.map($type::from_le_bytes)
+					.map_err(|_| ReadError)
+			}
+
+			#[inline]
+			fn try_write_be<W: BytesWrite>(
+				self,
+				w: &mut W
+			) -> Result<(), WriteError> {
+				w.try_write(self.to_be_bytes())
+			}
+
+			#[inline]
+			fn try_write_le<W: BytesWrite>(
+				self,
+				w: &mut W
+			) -> Result<(), WriteError> {
+				w.try_write(self.to_le_bytes())
+			}
+		}
+	)*)
+}
+
+/// Used by `read_struct!` and `write_struct!`.
+#[doc(hidden)]
+pub trait StructField: Sized {
+	fn try_read_be<R: BytesRead>(r: &mut R) -> Result<Self, ReadError>;
+
+	fn try_read_le<R: BytesRead>(r: &mut R) -> Result<Self, ReadError>;
+
+	fn try_write_be<W: BytesWrite>(self, w: &mut W) -> Result<(), WriteError>;
+
+	fn try_write_le<W: BytesWrite>(self, w: &mut W) -> Result<(), WriteError>;
+}
+
+struct_field_impl!(
+	u8, u16, u32, u64, u128,
+	i8, i16, i32, i64, i128,
+	f32, f64
+);
+
+
+#[cfg(test)]
+mod tests {
+	use crate::{BytesMut, BytesOwned, BytesRead, BytesSeek, WriteError};
+
+	#[derive(Debug, PartialEq)]
+	struct Header {
+		magic: u32,
+		version: u16,
+		flags: u8
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut bytes = BytesOwned::new();
+		write_struct!(bytes, le: { magic: u32 = 1, version: u16 = 2, flags: u8 = 3 })
+			.unwrap();
+		assert_eq!(bytes.as_slice(), &[1, 0, 0, 0, 2, 0, 3]);
+
+		bytes.seek(0);
+		let header = read_struct!(bytes, le: Header {
+			magic: u32,
+			version: u16,
+			flags: u8
+		}).unwrap();
+		assert_eq!(header, Header { magic: 1, version: 2, flags: 3 });
+
+		bytes.seek(1);
+		let res = read_struct!(bytes, be: Header {
+			magic: u32,
+			version: u16,
+			flags: u8
+		});
+		assert!(res.is_err());
+		assert_eq!(bytes.position(), 1);
+	}
+
+	#[test]
+	fn rollback() {
+		let mut buf = [0u8; 5];
+		let mut bytes = BytesMut::from_array(&mut buf);
+		bytes.seek(1);
+		let res = write_struct!(bytes, be: { a: u16 = 1, b: u16 = 2, c: u8 = 3 });
+		assert_eq!(res, Err(WriteError));
+		assert_eq!(bytes.position(), 1);
+
+		let w = &mut bytes;
+		write_struct!(*w, be: { a: u16 = 1, b: u16 = 2 }).unwrap();
+		assert_eq!(buf, [0, 0, 1, 0, 2]);
+	}
+}
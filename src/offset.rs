@@ -1,6 +1,7 @@
 
 use crate::{
-	BytesRead, ReadError, BytesWrite, WriteError, BytesSeek, SeekError, Bytes
+	BytesRead, ReadError, BytesWrite, WriteError, BytesSeek, SeekError, Bytes,
+	BytesBase
 };
 
 /// A struct which holds a specific offset for any BytesRead,
@@ -39,6 +40,17 @@ impl<T> Offset<T> {
 		Self { inner, offset }
 	}
 
+	/// Creates a new Offset if the inner position can be advanced by
+	/// `offset`.
+	///
+	/// ## Fails
+	/// If the inner value can't seek to the offset.
+	pub fn try_new(mut inner: T, offset: usize) -> Result<Self, SeekError>
+	where T: BytesRead + BytesSeek {
+		inner.try_advance(offset)?;
+		Ok(Self { inner, offset })
+	}
+
 	/// Updates the offset.
 	///
 	/// Maybe panic if there aren't enough bytes left.
@@ -77,7 +89,7 @@ impl<T> BytesRead for Offset<T>
 where T: BytesRead {
 	#[inline]
 	fn as_slice(&self) -> &[u8] {
-		&self.inner.as_slice()[self.offset..]
+		self.inner.as_slice().get(self.offset..).unwrap_or(&[])
 	}
 
 	#[inline]
@@ -101,6 +113,11 @@ where T: BytesSeek {
 		self.inner.position() - self.offset
 	}
 
+	/// Returns the position in the underlying buffer.
+	fn absolute_position(&self) -> usize {
+		self.inner.absolute_position()
+	}
+
 	/// Sets the internal position.
 	/// 
 	/// ## Panics
@@ -113,11 +130,11 @@ where T: BytesSeek {
 impl<T> BytesWrite for Offset<T>
 where T: BytesWrite {
 	fn as_mut(&mut self) -> &mut [u8] {
-		&mut self.inner.as_mut()[self.offset..]
+		self.inner.as_mut().get_mut(self.offset..).unwrap_or(&mut [])
 	}
 
 	fn as_bytes(&self) -> Bytes<'_> {
-		self.inner.as_bytes().inner()[self.offset..].into()
+		self.inner.as_bytes().inner().get(self.offset..).unwrap_or(&[]).into()
 	}
 
 	fn remaining_mut(&mut self) -> &mut [u8] {
@@ -127,15 +144,57 @@ where T: BytesWrite {
 	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
 		self.inner.try_write(slice)
 	}
+
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		self.inner.try_write_vectored(slices)
+	}
+
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		self.inner.write_partial(slice)
+	}
+}
+
+impl<T> From<(T, usize)> for Offset<T>
+where T: BytesRead + BytesSeek {
+	/// Same as `Offset::new`.
+	///
+	/// ## Example
+	/// ```
+	/// # use simple_bytes::{Offset, LimitedReader, Bytes, BytesRead};
+	/// let bytes = Bytes::from(&[1u8, 2, 3, 4][..]);
+	/// let mut stack: Offset<LimitedReader<Bytes>> = (
+	///     LimitedReader::from((bytes, 1)),
+	///     2
+	/// ).into();
+	/// assert_eq!(stack.read_u8(), 3);
+	/// assert!(stack.try_read(2).is_err());
+	/// ```
+	fn from((inner, offset): (T, usize)) -> Self {
+		Self::new(inner, offset)
+	}
 }
 
+impl<T> BytesBase for Offset<T>
+where T: BytesBase {
+	type Base = T::Base;
+
+	#[inline]
+	fn base(&self) -> &T::Base {
+		self.inner.base()
+	}
+
+	#[inline]
+	fn base_mut(&mut self) -> &mut T::Base {
+		self.inner.base_mut()
+	}
+}
 
 
 #[cfg(test)]
 mod tests {
 
 	use super::*;
-	use crate::Cursor;
+	use crate::{Cursor, BytesOwned, LimitedReader};
 
 	#[test]
 	fn write() {
@@ -143,16 +202,79 @@ mod tests {
 		let cursor = Cursor::new(vec![1, 2, 3, 4]);
 		let mut offset_cursor = Offset::new(cursor, 2);
 		assert_eq!(offset_cursor.remaining_mut().len(), 2);
-		offset_cursor.write(&[1]);
+		offset_cursor.write([1]);
 		assert_eq!(offset_cursor.remaining_mut().len(), 1);
-		offset_cursor.write(&[2]);
+		offset_cursor.write([2]);
 		assert_eq!(offset_cursor.remaining_mut().len(), 0);
-		offset_cursor.write(&[1, 2]);
+		offset_cursor.write([1, 2]);
 
 		assert_eq!(offset_cursor.as_mut(), &[1, 2, 1, 2]);
 
 	}
 
+	#[test]
+	fn base() {
+		let mut nested = Offset::new(
+			LimitedReader::new(BytesOwned::from(vec![1, 2, 3]), 1),
+			1
+		);
+		assert_eq!(nested.inner().inner().as_slice(), &[1, 2, 3]);
+		nested.base_mut().seek(2);
+		assert_eq!(nested.position(), 1);
+		assert_eq!(nested.read_u8(), 3);
+		assert_eq!(nested.base().position(), 3);
+	}
+
+	#[test]
+	fn offset_past_end() {
+		let mut vec = vec![1];
+		let mut offset = Offset::new(Cursor::new(&mut vec), 3);
+		assert_eq!(offset.as_slice(), &[]);
+		assert_eq!(offset.as_mut(), &[]);
+		assert_eq!(offset.position(), 0);
+		offset.write_u8(2);
+		assert_eq!(offset.as_slice(), &[2]);
+		assert_eq!(vec, [1, 0, 0, 2]);
+	}
+
+	#[test]
+	fn generic_write() {
+		use crate::{BytesMut, BytesArray, SecretBytes};
+
+		fn write<W: BytesWrite>(w: &mut W) {
+			w.write([1]);
+			w.write(vec![2]);
+			w.write_u16(3);
+		}
+
+		let mut buf = [0u8; 8];
+		let mut bytes = BytesMut::from(&mut buf[..]);
+		write(&mut bytes);
+		let mut offset = Offset::new(bytes, 0);
+		write(&mut offset);
+		assert_eq!(buf, [1, 2, 0, 3, 1, 2, 0, 3]);
+
+		let mut buf = [0u8; 4];
+		write(&mut Cursor::new(&mut buf[..]));
+		assert_eq!(buf, [1, 2, 0, 3]);
+
+		let mut vec = vec![];
+		write(&mut Cursor::new(&mut vec));
+		assert_eq!(vec, [1, 2, 0, 3]);
+
+		let mut offset = Offset::new(BytesOwned::from(vec![9]), 1);
+		write(&mut offset);
+		assert_eq!(offset.into_inner().into_vec(), [9, 1, 2, 0, 3]);
+
+		let mut arr = BytesArray::from([0u8; 4]);
+		write(&mut arr);
+		assert_eq!(arr.into_array(), [1, 2, 0, 3]);
+
+		let mut secret = SecretBytes::new();
+		write(&mut secret);
+		assert_eq!(secret.as_slice(), [1, 2, 0, 3]);
+	}
+
 	#[test]
 	fn read() {
 
@@ -164,6 +286,11 @@ mod tests {
 		assert_eq!(offset_cursor.as_slice(), &[3, 4]);
 		assert_eq!(offset_cursor.remaining(), &[4]);
 
+		assert_eq!(offset_cursor.absolute_position(), 3);
+		let nested = Offset::new(offset_cursor.clone(), 1);
+		assert_eq!(nested.position(), 1);
+		assert_eq!(nested.absolute_position(), 4);
+
 		offset_cursor.set_offset(1);
 		assert_eq!(offset_cursor.position(), 1);
 		assert_eq!(offset_cursor.as_slice(), &[2, 3, 4]);
@@ -171,4 +298,12 @@ mod tests {
 
 	}
 
-}
\ No newline at end of file
+	#[test]
+	fn constructors() {
+		let bytes = Bytes::from_array(&[1u8, 2, 3]);
+		let offset: Offset<_> = (bytes, 1).into();
+		assert_eq!(offset, Offset::new(bytes, 1));
+		assert_eq!(Offset::try_new(bytes, 3).unwrap().remaining(), &[]);
+		assert!(Offset::try_new(bytes, 4).is_err());
+	}
+}
@@ -0,0 +1,179 @@
+//! # Note
+//! Growing the buffer may leave copies of the old allocation behind,
+//! use `SecretBytes::with_capacity` if the size is known.
+
+use crate::{
+	Bytes, BytesOwned,
+	BytesRead, ReadError,
+	BytesWrite, WriteError,
+	BytesSeek, SeekError
+};
+
+use std::{fmt, ptr};
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// A BytesOwned wrapper that overwrites its bytes with zeros when
+/// dropped.
+///
+/// Useful for key material or other sensitive data. `Debug` and `Display`
+/// only print the length, never the content.
+pub struct SecretBytes {
+	inner: BytesOwned
+}
+
+impl SecretBytes {
+	/// Creates an empty SecretBytes.
+	pub fn new() -> Self {
+		Self {
+			inner: BytesOwned::new()
+		}
+	}
+
+	/// Creates a new SecretBytes with the given capacity.
+	pub fn with_capacity(cap: usize) -> Self {
+		Self {
+			inner: BytesOwned::with_capacity(cap)
+		}
+	}
+
+	/// Overwrites the entire allocation with zeros, keeping the length
+	/// and position.
+	pub fn zeroize(&mut self) {
+		let vec = self.inner.as_mut_vec();
+		let ptr = vec.as_mut_ptr();
+		for i in 0..vec.capacity() {
+			// Safe because the pointer is valid for the entire capacity
+			// and u8 has no invalid bit patterns.
+			// The volatile write prevents the compiler from eliding it.
+			unsafe { ptr::write_volatile(ptr.add(i), 0) }
+		}
+		compiler_fence(Ordering::SeqCst);
+	}
+}
+
+impl Default for SecretBytes {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Debug for SecretBytes {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "SecretBytes([REDACTED; {}])", self.len())
+	}
+}
+
+impl fmt::Display for SecretBytes {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(self, f)
+	}
+}
+
+impl BytesRead for SecretBytes {
+	#[inline]
+	fn as_slice(&self) -> &[u8] {
+		self.inner.as_slice()
+	}
+
+	#[inline]
+	fn remaining(&self) -> &[u8] {
+		self.inner.remaining()
+	}
+
+	#[inline]
+	fn try_read(&mut self, len: usize) -> Result<&[u8], ReadError> {
+		self.inner.try_read(len)
+	}
+
+	#[inline]
+	fn peek(&self, len: usize) -> Option<&[u8]> {
+		self.inner.peek(len)
+	}
+}
+
+impl BytesWrite for SecretBytes {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [u8] {
+		self.inner.as_mut()
+	}
+
+	#[inline]
+	fn as_bytes(&self) -> Bytes<'_> {
+		self.inner.as_bytes()
+	}
+
+	#[inline]
+	fn remaining_mut(&mut self) -> &mut [u8] {
+		self.inner.remaining_mut()
+	}
+
+	#[inline]
+	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
+		self.inner.try_write(slice)
+	}
+
+	#[inline]
+	fn try_write_vectored(&mut self, slices: &[&[u8]]) -> Result<(), WriteError> {
+		self.inner.try_write_vectored(slices)
+	}
+
+	#[inline]
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		self.inner.write_partial(slice)
+	}
+}
+
+impl BytesSeek for SecretBytes {
+	/// Returns the internal position.
+	fn position(&self) -> usize {
+		self.inner.position()
+	}
+
+	/// Sets the internal position, allocating more space
+	/// if the position is bigger than the `Vec`.
+	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
+		self.inner.try_seek(pos)
+	}
+}
+
+impl From<Vec<u8>> for SecretBytes {
+	fn from(b: Vec<u8>) -> Self {
+		Self { inner: b.into() }
+	}
+}
+
+impl From<BytesOwned> for SecretBytes {
+	fn from(inner: BytesOwned) -> Self {
+		Self { inner }
+	}
+}
+
+impl Drop for SecretBytes {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn zeroize() {
+		let mut bytes = SecretBytes::with_capacity(8);
+		bytes.write_u32(u32::MAX);
+		assert_eq!(bytes.as_slice(), &[255; 4]);
+
+		bytes.zeroize();
+		assert_eq!(bytes.as_slice(), &[0; 4]);
+		assert_eq!(bytes.position(), 4);
+	}
+
+	#[test]
+	fn redacted() {
+		let bytes = SecretBytes::from(vec![1u8; 32]);
+		assert_eq!(format!("{:?}", bytes), "SecretBytes([REDACTED; 32])");
+		assert_eq!(bytes.to_string(), "SecretBytes([REDACTED; 32])");
+	}
+}
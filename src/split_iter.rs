@@ -0,0 +1,85 @@
+use crate::{Bytes, BytesRead, BytesReadRef};
+
+/// Reads pieces separated by a delimiter, consuming the delimiter.
+///
+/// Gets created by `BytesRead::split_iter`. Any reader can use
+/// `next_slice`, `Bytes` also implements `Iterator` returning slices
+/// with the original lifetime.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{Bytes, BytesRead};
+/// let mut bytes = Bytes::from_array(b"a,bc,");
+/// let pieces: Vec<_> = bytes.split_iter(b',').collect();
+/// assert_eq!(pieces, [b"a".as_ref(), b"bc"]);
+/// ```
+#[derive(Debug)]
+pub struct SplitIter<'r, R> {
+	inner: &'r mut R,
+	delim: u8
+}
+
+impl<'r, R> SplitIter<'r, R> {
+	pub(crate) fn new(inner: &'r mut R, delim: u8) -> Self {
+		Self { inner, delim }
+	}
+}
+
+impl<R> SplitIter<'_, R>
+where R: BytesRead {
+	/// Reads until the next delimiter or the end of the buffer.
+	///
+	/// The returned slice does not contain the delimiter and is valid
+	/// until the next call. Returns `None` if there are no bytes remaining.
+	pub fn next_slice(&mut self) -> Option<&[u8]> {
+		let (len, consumed) = split_len(self.inner.remaining(), self.delim)?;
+		self.inner.try_read(consumed).ok().map(|s| &s[..len])
+	}
+}
+
+impl<'a> Iterator for SplitIter<'_, Bytes<'a>> {
+	type Item = &'a [u8];
+
+	fn next(&mut self) -> Option<&'a [u8]> {
+		let (len, consumed) = split_len(self.inner.remaining(), self.delim)?;
+		self.inner.try_read_ref(consumed).ok().map(|s| &s[..len])
+	}
+}
+
+// returns the length of the piece and how many bytes need to be consumed
+fn split_len(remaining: &[u8], delim: u8) -> Option<(usize, usize)> {
+	if remaining.is_empty() {
+		return None
+	}
+
+	let len = remaining.iter()
+		.position(|b| *b == delim)
+		.map(|i| (i, i + 1))
+		.unwrap_or((remaining.len(), remaining.len()));
+
+	Some(len)
+}
+
+
+#[cfg(test)]
+mod tests {
+	use crate::{Bytes, BytesOwned, BytesRead};
+
+	#[test]
+	fn iter() {
+		let mut bytes = Bytes::from_array(b",a,,bc");
+		let pieces: Vec<_> = bytes.split_iter(b',').collect();
+		assert_eq!(pieces, [&b""[..], b"a", b"", b"bc"]);
+		assert!(bytes.remaining().is_empty());
+	}
+
+	#[test]
+	fn next_slice() {
+		let mut bytes = BytesOwned::from(b"ab\ncd\n".to_vec());
+		let mut split = bytes.split_iter(b'\n');
+		assert_eq!(split.next_slice().unwrap(), b"ab");
+		assert_eq!(split.next_slice().unwrap(), b"cd");
+		assert!(split.next_slice().is_none());
+		assert!(bytes.remaining().is_empty());
+	}
+}
@@ -0,0 +1,34 @@
+
+use crate::{BytesWrite, WriteError};
+
+macro_rules! to_bytes_impl {
+	($($type:ident => $try_name:ident),*) => ($(
+		impl ToBytes for $type {
+			/// Writes the number in big-endian.
+			#[inline]
+			fn to_bytes<W: BytesWrite>(&self, w: &mut W) -> Result<(), WriteError> {
+				w.$try_name(*self)
+			}
+		}
+	)*)
+}
+
+/// A type that can be written to a `BytesWrite`.
+///
+/// Numbers are written in big-endian.
+pub trait ToBytes {
+	/// Writes `self` to `w`.
+	///
+	/// ## Fails
+	/// If there isn't enough space left, on failure some bytes may
+	/// have been written.
+	fn to_bytes<W: BytesWrite>(&self, w: &mut W) -> Result<(), WriteError>;
+}
+
+to_bytes_impl!(
+	u8 => try_write_u8, u16 => try_write_u16, u32 => try_write_u32,
+	u64 => try_write_u64, u128 => try_write_u128,
+	i8 => try_write_i8, i16 => try_write_i16, i32 => try_write_i32,
+	i64 => try_write_i64, i128 => try_write_i128,
+	f32 => try_write_f32, f64 => try_write_f64
+);
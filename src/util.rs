@@ -1,6 +1,10 @@
+//! Small helpers to assemble or split numbers.
+
+use crate::SeekFromError;
 
 use std::io;
 use std::error::Error;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub(crate) fn io_other<E>(error: E) -> io::Error
 where E: Into<Box<dyn Error + Send + Sync>> {
@@ -12,6 +16,11 @@ where E: Into<Box<dyn Error + Send + Sync>> {
 	io::Error::new(io::ErrorKind::UnexpectedEof, error)
 }
 
+pub(crate) fn io_write_zero<E>(error: E) -> io::Error
+where E: Into<Box<dyn Error + Send + Sync>> {
+	io::Error::new(io::ErrorKind::WriteZero, error)
+}
+
 pub(crate) fn seek_from_to_n_pos(
 	inner_len: usize,
 	pos: usize,
@@ -21,12 +30,14 @@ pub(crate) fn seek_from_to_n_pos(
 		io::SeekFrom::Start(start) => start.try_into().map_err(io_eof)?,
 		io::SeekFrom::End(end) => {
 			let max: i64 = inner_len.try_into().map_err(io_other)?;
-			let new = max - end;
+			let new = max.checked_add(end)
+				.ok_or_else(|| io_other("seek position overflowed"))?;
 			new.try_into().map_err(io_eof)?
 		},
 		io::SeekFrom::Current(curr) => {
 			let pos: i64 = pos.try_into().map_err(io_other)?;
-			let new = pos + curr;
+			let new = pos.checked_add(curr)
+				.ok_or_else(|| io_other("seek position overflowed"))?;
 			new.try_into().map_err(io_eof)?
 		}
 	};
@@ -34,25 +45,267 @@ pub(crate) fn seek_from_to_n_pos(
 	Ok(n_pos)
 }
 
+// like seek_from_to_n_pos but returns a SeekFromError, the position is
+// not checked
+pub(crate) fn seek_from_to_pos(
+	inner_len: usize,
+	pos: usize,
+	seek_from: io::SeekFrom
+) -> Result<usize, SeekFromError> {
+	let (base, offset) = match seek_from {
+		io::SeekFrom::Start(start) => (0, start as i128),
+		io::SeekFrom::End(end) => (inner_len, end as i128),
+		io::SeekFrom::Current(curr) => (pos, curr as i128)
+	};
+
+	// can't overflow since both fit into 64 bits
+	let n_pos = base as i128 + offset;
+	if n_pos < 0 {
+		return Err(SeekFromError::BeforeStart)
+	}
+
+	n_pos.try_into().map_err(|_| SeekFromError::Overflow)
+}
+
 // returns the new position
+//
+// if pos is past the end the gap gets filled with zeros
+//
+// the capacity is ensured before anything gets overwritten, so if the
+// allocation fails the vec is left untouched
 pub(crate) fn write_or_alloc(
 	vec: &mut Vec<u8>,
 	pos: usize,
 	slice: &[u8]
 ) -> usize {
+	let end = pos.checked_add(slice.len())
+		.expect("capacity overflow");
+
+	if end > vec.len() {
+		vec.reserve(end - vec.len());
+	}
+
+	if vec.len() < pos {
+		vec.resize(pos, 0);
+	}
+
 	let rem_len = vec.len() - pos;
 
 	// if has enough space
 	if slice.len() <= rem_len {
-		vec[pos..][..slice.len()].copy_from_slice(slice);
-		return pos + slice.len()
+		vec[pos..end].copy_from_slice(slice);
+		return end
 	}
 
 	// not enough space
-	if rem_len > 0 {
-		vec[pos..][..rem_len].copy_from_slice(&slice[..rem_len]);
+	vec[pos..].copy_from_slice(&slice[..rem_len]);
+	// does not allocate since the capacity was reserved
+	vec.extend_from_slice(&slice[rem_len..]);
+	end
+}
+
+// writes all slices at pos allocating the needed space only once, returns
+// the new position or None if it would overflow, nothing is written then
+pub(crate) fn write_vectored_or_alloc(
+	vec: &mut Vec<u8>,
+	pos: usize,
+	slices: &[&[u8]]
+) -> Option<usize> {
+	let end = slices.iter()
+		.try_fold(pos, |end, s| end.checked_add(s.len()))?;
+	if end > vec.len() {
+		vec.reserve(end - vec.len());
 	}
 
-	vec.extend_from_slice(&slice[rem_len..]);
-	pos + slice.len()
-}
\ No newline at end of file
+	Some(slices.iter().fold(pos, |pos, s| write_or_alloc(vec, pos, s)))
+}
+
+// expects a u32 count followed by that many u32 length prefixed key value
+// pairs, calls f for every pair and returns how many bytes it used
+pub(crate) fn split_kv_map_u32<'a, F>(slice: &'a [u8], mut f: F) -> Option<usize>
+where F: FnMut(&'a [u8], &'a [u8]) {
+	fn split_prefixed(slice: &[u8]) -> Option<(&[u8], &[u8])> {
+		let len = u32::from_be_bytes(slice.get(..4)?.try_into().unwrap());
+		let len: usize = len.try_into().ok()?;
+		let rest = &slice[4..];
+		if rest.len() < len {
+			return None
+		}
+
+		Some(rest.split_at(len))
+	}
+
+	let count = u32::from_be_bytes(slice.get(..4)?.try_into().unwrap());
+	let mut rest = &slice[4..];
+	for _ in 0..count {
+		let (key, r) = split_prefixed(rest)?;
+		let (value, r) = split_prefixed(r)?;
+		f(key, value);
+		rest = r;
+	}
+
+	Some(slice.len() - rest.len())
+}
+
+// returns the number and how many bytes it used
+pub(crate) fn decode_var_u64(slice: &[u8]) -> Option<(u64, usize)> {
+	let mut num = 0u64;
+	for (i, b) in slice.iter().take(10).enumerate() {
+		// the tenth byte can only contain one bit
+		if i == 9 && *b > 1 {
+			return None
+		}
+
+		num |= ((b & 0x7f) as u64) << (7 * i);
+		if b & 0x80 == 0 {
+			return Some((num, i + 1))
+		}
+	}
+
+	None
+}
+
+// returns how many bytes were used
+pub(crate) fn encode_var_u64(mut num: u64, buf: &mut [u8; 10]) -> usize {
+	let mut i = 0;
+	loop {
+		let b = (num & 0x7f) as u8;
+		num >>= 7;
+		if num == 0 {
+			buf[i] = b;
+			return i + 1
+		}
+
+		buf[i] = b | 0x80;
+		i += 1;
+	}
+}
+
+// swaps between the microsoft mixed-endian guid layout and the canonical
+// byte order, the first three fields are little-endian
+pub(crate) fn swap_guid_fields(mut guid: [u8; 16]) -> [u8; 16] {
+	guid[..4].reverse();
+	guid[4..6].reverse();
+	guid[6..8].reverse();
+	guid
+}
+
+// folds all bytes with xor
+pub(crate) fn xor_checksum(slice: &[u8]) -> u8 {
+	slice.iter().fold(0, |acc, b| acc ^ b)
+}
+
+// seconds between 1601-01-01 and 1970-01-01
+const FILETIME_UNIX_OFFSET: u64 = 11_644_473_600;
+// 100ns intervals per second
+const FILETIME_PER_SEC: u64 = 10_000_000;
+
+fn filetime_epoch() -> Option<SystemTime> {
+	UNIX_EPOCH.checked_sub(Duration::from_secs(FILETIME_UNIX_OFFSET))
+}
+
+// converts 100ns intervals since 1601-01-01
+pub(crate) fn filetime_to_system_time(ft: u64) -> Option<SystemTime> {
+	let dur = Duration::new(
+		ft / FILETIME_PER_SEC,
+		(ft % FILETIME_PER_SEC) as u32 * 100
+	);
+	filetime_epoch()?.checked_add(dur)
+}
+
+// rounds down to 100ns intervals
+pub(crate) fn system_time_to_filetime(time: SystemTime) -> Option<u64> {
+	let dur = time.duration_since(filetime_epoch()?).ok()?;
+	dur.as_secs()
+		.checked_mul(FILETIME_PER_SEC)?
+		.checked_add(dur.subsec_nanos() as u64 / 100)
+}
+
+// converts milliseconds since 1970-01-01, negative values are before
+pub(crate) fn unix_millis_to_system_time(ms: i64) -> Option<SystemTime> {
+	let dur = Duration::from_millis(ms.unsigned_abs());
+	if ms >= 0 {
+		UNIX_EPOCH.checked_add(dur)
+	} else {
+		UNIX_EPOCH.checked_sub(dur)
+	}
+}
+
+// rounds down to milliseconds, also before the epoch
+pub(crate) fn system_time_to_unix_millis(time: SystemTime) -> Option<i64> {
+	match time.duration_since(UNIX_EPOCH) {
+		Ok(dur) => dur.as_millis().try_into().ok(),
+		Err(e) => {
+			let dur = e.duration();
+			let mut ms = dur.as_millis() as i128;
+			if dur.subsec_nanos() % 1_000_000 != 0 {
+				ms += 1;
+			}
+			(-ms).try_into().ok()
+		}
+	}
+}
+
+/// Combines two halves into a `u64`, `hi` are the upper 32 bits.
+///
+/// ## Example
+/// A `f64` stored as two separate `u32` halves.
+/// ```
+/// # use simple_bytes::{Bytes, BytesRead};
+/// # use simple_bytes::util::combine_u32_to_u64;
+/// let mut bytes = Bytes::from_array(&[0x3f, 0xf8, 0, 0, 0, 0, 0, 0]);
+/// let hi = bytes.read_u32();
+/// let lo = bytes.read_u32();
+/// assert_eq!(f64::from_bits(combine_u32_to_u64(hi, lo)), 1.5);
+/// ```
+#[inline]
+pub fn combine_u32_to_u64(hi: u32, lo: u32) -> u64 {
+	(hi as u64) << 32 | lo as u64
+}
+
+/// Splits a `u64` into two halves, returning the upper and then the
+/// lower 32 bits.
+#[inline]
+pub fn split_u64_to_u32(num: u64) -> (u32, u32) {
+	((num >> 32) as u32, num as u32)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn combine_split() {
+		let num = combine_u32_to_u64(0x01020304, 0x05060708);
+		assert_eq!(num, 0x0102030405060708);
+		assert_eq!(split_u64_to_u32(num), (0x01020304, 0x05060708));
+	}
+
+	#[test]
+	fn write_or_alloc_reserves_first() {
+		let mut vec = vec![1, 2, 3];
+		assert_eq!(write_or_alloc(&mut vec, 1, &[4]), 2);
+		assert_eq!(vec, [1, 4, 3]);
+
+		// overlapping the end
+		let cap = vec.capacity();
+		assert_eq!(write_or_alloc(&mut vec, 2, &[5; 10]), 12);
+		assert!(vec.capacity() >= 12 && vec.capacity() > cap);
+		assert_eq!(vec, [1, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5]);
+
+		// with a gap
+		assert_eq!(write_or_alloc(&mut vec, 14, &[6]), 15);
+		assert_eq!(&vec[11..], &[5, 0, 0, 6]);
+	}
+
+
+	#[test]
+	fn write_vectored_or_alloc_overflow() {
+		let mut vec = vec![1];
+		assert_eq!(write_vectored_or_alloc(&mut vec, 2, &[&[2], &[3, 4]]), Some(5));
+		assert_eq!(vec, [1, 0, 2, 3, 4]);
+
+		assert_eq!(write_vectored_or_alloc(&mut vec, usize::MAX, &[&[5]]), None);
+		assert_eq!(vec, [1, 0, 2, 3, 4]);
+	}
+}
@@ -0,0 +1,190 @@
+
+use crate::{Bytes, BytesWrite, WriteError, BytesSeek, SeekError};
+
+use std::{ptr, slice};
+
+/// A writer into the spare capacity of a `Vec`.
+///
+/// Unlike `Cursor<&mut Vec<u8>>` this never allocates and never
+/// initializes the spare capacity, writing past the capacity fails.
+/// The written bytes only get added to the `Vec` when calling `commit`.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{VecWriter, BytesWrite};
+/// let mut vec = Vec::with_capacity(4);
+/// vec.push(1);
+///
+/// let mut writer = VecWriter::new(&mut vec);
+/// writer.write_u16(2);
+/// assert!(writer.try_write_u16(3).is_err());
+/// writer.commit();
+///
+/// assert_eq!(vec, [1, 0, 2]);
+/// ```
+#[derive(Debug)]
+pub struct VecWriter<'a> {
+	inner: &'a mut Vec<u8>,
+	// the len of the vec before writing
+	start: usize,
+	position: usize,
+	// how many bytes after start are initialized
+	init: usize
+}
+
+impl<'a> VecWriter<'a> {
+	/// Creates a new VecWriter starting at the end of the `Vec`.
+	pub fn new(inner: &'a mut Vec<u8>) -> Self {
+		let start = inner.len();
+		Self { inner, start, position: 0, init: 0 }
+	}
+
+	/// Returns how many bytes can be written in total.
+	pub fn capacity(&self) -> usize {
+		self.inner.capacity() - self.start
+	}
+
+	/// Returns how many bytes were written.
+	pub fn written_len(&self) -> usize {
+		self.init
+	}
+
+	/// Adds the written bytes to the `Vec`, returning the new length.
+	pub fn commit(self) -> usize {
+		let len = self.start + self.init;
+		// safe because the bytes up to init were written
+		unsafe {
+			self.inner.set_len(len);
+		}
+
+		len
+	}
+}
+
+impl BytesWrite for VecWriter<'_> {
+	/// Returns the written bytes.
+	fn as_mut(&mut self) -> &mut [u8] {
+		// safe because the bytes up to init were written and
+		// init never exceeds the capacity
+		unsafe {
+			slice::from_raw_parts_mut(
+				self.inner.as_mut_ptr().add(self.start),
+				self.init
+			)
+		}
+	}
+
+	/// Returns the written bytes.
+	fn as_bytes(&self) -> Bytes<'_> {
+		// safe because the bytes up to init were written and
+		// init never exceeds the capacity
+		let slice = unsafe {
+			slice::from_raw_parts(
+				self.inner.as_ptr().add(self.start),
+				self.init
+			)
+		};
+
+		Bytes::new(0, slice)
+	}
+
+	/// Returns the written bytes after the position.
+	///
+	/// If an empty slice is returned, this does not mean
+	/// you can't write anymore.
+	fn remaining_mut(&mut self) -> &mut [u8] {
+		let pos = self.position;
+		&mut self.as_mut()[pos..]
+	}
+
+	/// Writes a slice into the spare capacity.
+	///
+	/// ## Fails
+	/// If the slice does not fit into the capacity, in which case
+	/// nothing is written.
+	fn try_write(&mut self, slice: impl AsRef<[u8]>) -> Result<(), WriteError> {
+		let slice = slice.as_ref();
+		let end = self.position.checked_add(slice.len())
+			.filter(|end| *end <= self.capacity())
+			.ok_or(WriteError)?;
+
+		// safe because end does not exceed the capacity
+		unsafe {
+			ptr::copy_nonoverlapping(
+				slice.as_ptr(),
+				self.inner.as_mut_ptr().add(self.start + self.position),
+				slice.len()
+			);
+		}
+
+		self.position = end;
+		self.init = self.init.max(end);
+
+		Ok(())
+	}
+
+	fn write_partial(&mut self, slice: &[u8]) -> usize {
+		let len = slice.len().min(self.capacity() - self.position);
+		self.write(&slice[..len]);
+		len
+	}
+}
+
+impl BytesSeek for VecWriter<'_> {
+	fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Sets the internal position.
+	///
+	/// ## Fails
+	/// If the position exceeds the written bytes.
+	fn try_seek(&mut self, pos: usize) -> Result<(), SeekError> {
+		if pos <= self.init {
+			self.position = pos;
+			Ok(())
+		} else {
+			Err(SeekError { attempted: pos, max: self.init })
+		}
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn write() {
+		let mut vec = Vec::with_capacity(6);
+		vec.extend_from_slice(&[1, 2]);
+		let cap = vec.capacity();
+
+		let mut writer = VecWriter::new(&mut vec);
+		assert_eq!(writer.capacity(), cap - 2);
+		writer.write_u16(3);
+		assert_eq!(writer.as_mut(), &[0, 3]);
+		writer.seek(1);
+		writer.write_u8(4);
+		assert_eq!(writer.written_len(), 2);
+		assert!(writer.try_seek(3).is_err());
+		writer.seek(2);
+		assert_eq!(writer.write_partial(&[0; 10]), cap - 4);
+		assert!(writer.try_write_u8(0).is_err());
+		writer.seek(2);
+		assert_eq!(writer.commit(), cap);
+
+		assert_eq!(&vec[..4], &[1, 2, 0, 4]);
+		assert_eq!(vec.len(), cap);
+	}
+
+	#[test]
+	fn no_commit() {
+		let mut vec = Vec::with_capacity(2);
+		{
+			let mut writer = VecWriter::new(&mut vec);
+			writer.write_u8(1);
+		}
+		assert!(vec.is_empty());
+	}
+}
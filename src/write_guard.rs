@@ -0,0 +1,154 @@
+use crate::{BytesWrite, BytesSeek};
+
+use std::ops::{Deref, DerefMut};
+
+/// A guard which seeks back to where it was created when it gets
+/// dropped without calling `commit`.
+///
+/// Useful to write speculatively and discard everything on an error.
+///
+/// Only the position gets rolled back, the length stays the same. So on
+/// a growable backend like `BytesOwned` bytes appended past the original
+/// end are kept, use `zeroing` to at least overwrite them or truncate the
+/// `Vec` yourself.
+///
+/// ## Example
+/// ```
+/// # use simple_bytes::{BytesArray, BytesRead, BytesSeek, BytesWrite, WriteGuard};
+/// let mut bytes = BytesArray::from([0u8; 3]);
+///
+/// let mut guard = WriteGuard::new(&mut bytes);
+/// guard.write_u16(1);
+/// assert!(guard.try_write_u16(2).is_err());
+/// // dropped without commit
+/// drop(guard);
+/// assert_eq!(bytes.position(), 0);
+///
+/// let mut guard = WriteGuard::new(&mut bytes);
+/// guard.write_u16(1);
+/// guard.commit();
+/// assert_eq!(bytes.position(), 2);
+/// ```
+#[derive(Debug)]
+pub struct WriteGuard<'a, T>
+where T: BytesWrite + BytesSeek {
+	inner: &'a mut T,
+	start: usize,
+	zero: bool,
+	committed: bool
+}
+
+impl<'a, T> WriteGuard<'a, T>
+where T: BytesWrite + BytesSeek {
+	/// Creates a new WriteGuard starting at the current position.
+	pub fn new(inner: &'a mut T) -> Self {
+		let start = inner.position();
+		Self { inner, start, zero: false, committed: false }
+	}
+
+	/// Creates a new WriteGuard which additionally overwrites the written
+	/// bytes with zeros if it gets dropped without calling `commit`.
+	///
+	/// Appended bytes get zeroed but not removed.
+	pub fn zeroing(inner: &'a mut T) -> Self {
+		let mut guard = Self::new(inner);
+		guard.zero = true;
+		guard
+	}
+
+	/// Returns the position where the guard was created.
+	pub fn start(&self) -> usize {
+		self.start
+	}
+
+	/// Keeps everything that was written.
+	pub fn commit(mut self) {
+		self.committed = true;
+	}
+}
+
+impl<T> Deref for WriteGuard<'_, T>
+where T: BytesWrite + BytesSeek {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		self.inner
+	}
+}
+
+impl<T> DerefMut for WriteGuard<'_, T>
+where T: BytesWrite + BytesSeek {
+	fn deref_mut(&mut self) -> &mut T {
+		self.inner
+	}
+}
+
+impl<T> Drop for WriteGuard<'_, T>
+where T: BytesWrite + BytesSeek {
+	fn drop(&mut self) {
+		if self.committed {
+			return
+		}
+
+		if self.zero {
+			let end = self.inner.position();
+			let slice = self.inner.as_mut();
+			let end = end.min(slice.len());
+			if let Some(written) = slice.get_mut(self.start..end) {
+				written.iter_mut().for_each(|b| *b = 0);
+			}
+		}
+
+		// the start was a valid position
+		let _ = self.inner.try_seek(self.start);
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BytesMut, BytesOwned, BytesRead};
+
+	#[test]
+	fn rollback() {
+		let mut buf = [0u8; 4];
+		let mut bytes = BytesMut::from(&mut buf[..]);
+		bytes.write_u8(1);
+		{
+			let mut guard = WriteGuard::new(&mut bytes);
+			assert_eq!(guard.start(), 1);
+			guard.write_u16(2);
+		}
+		assert_eq!(bytes.position(), 1);
+		{
+			let mut guard = WriteGuard::zeroing(&mut bytes);
+			guard.write_u16(3);
+		}
+		assert_eq!(bytes.position(), 1);
+		assert_eq!(buf, [1, 0, 0, 0]);
+	}
+
+	#[test]
+	fn commit() {
+		let mut bytes = BytesOwned::new();
+		let mut guard = WriteGuard::zeroing(&mut bytes);
+		guard.write_u16(3);
+		guard.commit();
+		assert_eq!(bytes.as_slice(), &[0, 3]);
+		assert_eq!(bytes.position(), 2);
+	}
+
+
+	#[test]
+	fn rollback_keeps_len() {
+		let mut bytes = BytesOwned::from(vec![1]);
+		bytes.seek(1);
+		{
+			let mut guard = WriteGuard::zeroing(&mut bytes);
+			guard.write_u16(3);
+		}
+		assert_eq!(bytes.position(), 1);
+		assert_eq!(bytes.as_slice(), &[1, 0, 0]);
+	}
+}